@@ -1,20 +1,30 @@
-use crate::alsw::{BASE32HEX_ALSW_CHECK_X2, BASE32_ALSW_CHECK_X2};
 use crate::decode::{decode_bits, decode_extra};
-use crate::decode::{BASE32HEX_TABLE, BASE32_TABLE};
+use crate::decode::{BASE32HEX_CASE_INSENSITIVE_TABLE, BASE32_CASE_INSENSITIVE_TABLE};
+use crate::decode::{BASE32HEX_TABLE, BASE32_TABLE, CROCKFORD_TABLE, GEOHASH_TABLE, ZBASE32_TABLE};
+use crate::lookup::{LookupLutX2, GEOHASH_LOOKUP_X2, ZBASE32_LOOKUP_X2};
 use crate::Error;
 use crate::Kind;
 
 use vsimd::alsw::AlswLut;
+use vsimd::base32::crockford_fold;
+use vsimd::base32::{BASE32HEX_ALSW_CHECK_X2, BASE32_ALSW_CHECK_X2, CROCKFORD_ALSW_CHECK_X2};
+use vsimd::base32::{BASE32HEX_CASE_INSENSITIVE_ALSW_CHECK_X2, BASE32_CASE_INSENSITIVE_ALSW_CHECK_X2};
 use vsimd::vector::V256;
 use vsimd::SIMD256;
 
 use core::ptr::null_mut;
 
 #[inline(always)]
-pub(crate) unsafe fn check_fallback(mut src: *const u8, mut len: usize, kind: Kind) -> Result<(), Error> {
+pub(crate) unsafe fn check_fallback(mut src: *const u8, mut len: usize, kind: &Kind) -> Result<(), Error> {
     let table = match kind {
         Kind::Base32 => BASE32_TABLE.as_ptr(),
         Kind::Base32Hex => BASE32HEX_TABLE.as_ptr(),
+        Kind::Base32CaseInsensitive => BASE32_CASE_INSENSITIVE_TABLE.as_ptr(),
+        Kind::Base32HexCaseInsensitive => BASE32HEX_CASE_INSENSITIVE_TABLE.as_ptr(),
+        Kind::Crockford => CROCKFORD_TABLE.as_ptr(),
+        Kind::ZBase32 => ZBASE32_TABLE.as_ptr(),
+        Kind::Geohash => GEOHASH_TABLE.as_ptr(),
+        Kind::Custom(custom) => custom.table.as_ptr(),
     };
 
     let end = src.add(len / 8 * 8);
@@ -29,15 +39,33 @@ pub(crate) unsafe fn check_fallback(mut src: *const u8, mut len: usize, kind: Ki
 }
 
 #[inline(always)]
-pub(crate) unsafe fn check_simd<S: SIMD256>(s: S, mut src: *const u8, mut len: usize, kind: Kind) -> Result<(), Error> {
+pub(crate) unsafe fn check_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    mut len: usize,
+    kind: &Kind,
+) -> Result<(), Error> {
     let check_lut = match kind {
         Kind::Base32 => BASE32_ALSW_CHECK_X2,
         Kind::Base32Hex => BASE32HEX_ALSW_CHECK_X2,
+        Kind::Base32CaseInsensitive => BASE32_CASE_INSENSITIVE_ALSW_CHECK_X2,
+        Kind::Base32HexCaseInsensitive => BASE32HEX_CASE_INSENSITIVE_ALSW_CHECK_X2,
+        Kind::Crockford => CROCKFORD_ALSW_CHECK_X2,
+        Kind::ZBase32 => return check_lookup_simd(s, src, len, kind, &ZBASE32_LOOKUP_X2),
+        Kind::Geohash => return check_lookup_simd(s, src, len, kind, &GEOHASH_LOOKUP_X2),
+        Kind::Custom(custom) => match custom.alsw {
+            Some((check_lut, _)) => check_lut.x2(),
+            None => return check_fallback(src, len, kind),
+        },
     };
+    let fold = matches!(kind, Kind::Crockford);
 
     let end = src.add(len / 32 * 32);
     while src < end {
-        let x = s.v256_load_unaligned(src);
+        let mut x = s.v256_load_unaligned(src);
+        if fold {
+            x = crockford_fold(s, x);
+        }
 
         let is_valid = check_ascii32(s, x, check_lut);
         ensure!(is_valid);
@@ -53,3 +81,23 @@ pub(crate) unsafe fn check_simd<S: SIMD256>(s: S, mut src: *const u8, mut len: u
 fn check_ascii32<S: SIMD256>(s: S, x: V256, check: AlswLut<V256>) -> bool {
     vsimd::alsw::check_ascii_xn(s, x, check)
 }
+
+#[inline(always)]
+unsafe fn check_lookup_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    mut len: usize,
+    kind: &Kind,
+    lut: &LookupLutX2,
+) -> Result<(), Error> {
+    let end = src.add(len / 32 * 32);
+    while src < end {
+        let x = s.v256_load_unaligned(src);
+        let y = crate::lookup::lookup_ascii32(s, x, lut);
+        ensure!(!vsimd::mask::u8x32_highbit_any(s, y));
+        src = src.add(32);
+    }
+    len %= 32;
+
+    check_fallback(src, len, kind)
+}
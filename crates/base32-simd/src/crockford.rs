@@ -0,0 +1,209 @@
+//! Crockford's Base32 with hyphens and check symbols.
+//!
+//! [`CROCKFORD`](crate::CROCKFORD) handles plain Crockford strings.
+//! The functions in this module additionally ignore hyphens and support the optional check symbol.
+//!
+//! The check symbol is the encoded number modulo 37.
+//! The encoded number is the value of the 5-bit symbols, including the zero padding bits of the last symbol.
+//! The values `32..=36` are written as `*`, `~`, `$`, `=` and `U`.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use base32_simd::crockford;
+//!
+//! let encoded = crockford::encode_with_check_to_string(b"hello");
+//! assert_eq!(encoded, "D1JPRV3FJ");
+//!
+//! let decoded = crockford::decode_to_vec("d1jp-rv3f-j", true).unwrap();
+//! assert_eq!(decoded, b"hello");
+//! # }
+//! ```
+
+use crate::decode::{unpadded_decoded_length, CROCKFORD_TABLE};
+use crate::{Error, Kind, Out, CROCKFORD, CROCKFORD_CHARSET};
+
+#[cfg(feature = "alloc")]
+use crate::AsOut;
+
+use vsimd::tools::{read, slice_mut};
+
+#[cfg(all(feature = "alloc", not(any(test, feature = "std"))))]
+use alloc::{string::String, vec::Vec};
+
+const CHECK_SYMBOLS: &[u8; 5] = b"*~$=U";
+
+const BUFFER_SIZE: usize = 256;
+
+/// Calculates the check symbol of `data`.
+#[inline]
+#[must_use]
+pub const fn check_symbol(data: &[u8]) -> u8 {
+    let mut r: u32 = 0;
+    let mut i = 0;
+    while i < data.len() {
+        r = (r * 256 + data[i] as u32) % 37;
+        i += 1;
+    }
+    // the last symbol is padded with zero bits
+    let padding = (5 - data.len() % 5 * 8 % 5) % 5;
+    let r = ((r << padding) % 37) as usize;
+    if r < 32 {
+        CROCKFORD_CHARSET[r]
+    } else {
+        CHECK_SYMBOLS[r - 32]
+    }
+}
+
+#[inline(always)]
+fn check_value(c: u8) -> u8 {
+    match c {
+        b'*' => 32,
+        b'~' => 33,
+        b'$' => 34,
+        b'=' => 35,
+        b'U' | b'u' => 36,
+        _ => unsafe { read(CROCKFORD_TABLE.as_ptr(), c as usize) },
+    }
+}
+
+/// Calculates the encoded length with a check symbol.
+///
+/// # Panics
+/// This function asserts that `n <= usize::MAX / 2`.
+#[inline]
+#[must_use]
+pub const fn encoded_length_with_check(n: usize) -> usize {
+    CROCKFORD.encoded_length(n) + 1
+}
+
+/// Encodes bytes to a Crockford string followed by its check symbol.
+///
+/// # Errors
+/// This function returns `Err` if the length of `dst` is not enough.
+#[inline]
+pub fn encode_with_check<'d>(src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    let m = encoded_length_with_check(src.len());
+    ensure!(dst.len() >= m);
+    unsafe {
+        let dst = dst.as_mut_ptr();
+        crate::multiversion::encode::auto(src.as_ptr(), src.len(), dst, &Kind::Crockford, false);
+        dst.add(m - 1).write(check_symbol(src));
+        Ok(slice_mut(dst, m))
+    }
+}
+
+/// Encodes bytes to a Crockford string followed by its check symbol.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[inline]
+#[must_use]
+pub fn encode_with_check_to_string(data: impl AsRef<[u8]>) -> String {
+    let src = data.as_ref();
+    let mut buf = CROCKFORD.encode_to_string(src);
+    buf.push(check_symbol(src) as char);
+    buf
+}
+
+/// Splits the last non-hyphen character from `src` if `check` is true.
+#[inline(always)]
+fn split_check(src: &[u8], check: bool) -> Result<(&[u8], Option<u8>), Error> {
+    if !check {
+        return Ok((src, None));
+    }
+    let Some(pos) = src.iter().rposition(|&c| c != b'-') else {
+        return Err(Error::new());
+    };
+    Ok((&src[..pos], Some(src[pos])))
+}
+
+#[inline(always)]
+fn count_symbols(data: &[u8]) -> usize {
+    data.iter().filter(|&&c| c != b'-').count()
+}
+
+/// Calculates the decoded length, ignoring hyphens.
+///
+/// # Errors
+/// This function returns `Err` if the content of `data` is partially invalid.
+#[inline]
+pub fn decoded_length(data: &[u8], check: bool) -> Result<usize, Error> {
+    let (data, _) = split_check(data, check)?;
+    unpadded_decoded_length(count_symbols(data))
+}
+
+/// Decodes `n` symbols from `data`, skipping hyphens.
+unsafe fn decode_raw(data: &[u8], n: usize, dst: *mut u8) -> Result<(), Error> {
+    if n == data.len() {
+        return crate::multiversion::decode::auto(data.as_ptr(), n, dst, &Kind::Crockford);
+    }
+
+    let mut buf = [0u8; BUFFER_SIZE];
+    let mut len = 0;
+    let mut dst = dst;
+
+    for mut part in data.split(|&c| c == b'-') {
+        while !part.is_empty() {
+            let k = part.len().min(BUFFER_SIZE - len);
+            buf[len..len + k].copy_from_slice(&part[..k]);
+            len += k;
+            part = &part[k..];
+
+            if len == BUFFER_SIZE {
+                crate::multiversion::decode::auto(buf.as_ptr(), len, dst, &Kind::Crockford)?;
+                dst = dst.add(BUFFER_SIZE / 8 * 5);
+                len = 0;
+            }
+        }
+    }
+
+    crate::multiversion::decode::auto(buf.as_ptr(), len, dst, &Kind::Crockford)
+}
+
+/// Decodes a Crockford string to bytes.
+///
+/// Hyphens are ignored. If `check` is true, the last character must be the check symbol.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid.
+/// + the check symbol does not match.
+#[inline]
+pub fn decode<'d>(src: &[u8], mut dst: Out<'d, [u8]>, check: bool) -> Result<&'d mut [u8], Error> {
+    let (data, symbol) = split_check(src, check)?;
+    let n = count_symbols(data);
+    let m = unpadded_decoded_length(n)?;
+    ensure!(dst.len() >= m);
+    unsafe {
+        let dst = dst.as_mut_ptr();
+        decode_raw(data, n, dst)?;
+        let ans = slice_mut(dst, m);
+        if let Some(c) = symbol {
+            ensure!(check_value(c) == check_value(check_symbol(ans)));
+        }
+        Ok(ans)
+    }
+}
+
+/// Decodes a Crockford string to bytes.
+///
+/// Hyphens are ignored. If `check` is true, the last character must be the check symbol.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the content of `data` is invalid.
+/// + the check symbol does not match.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn decode_to_vec(data: impl AsRef<[u8]>, check: bool) -> Result<Vec<u8>, Error> {
+    let src = data.as_ref();
+    let m = decoded_length(src, check)?;
+    let mut buf: Vec<u8> = Vec::with_capacity(m);
+    decode(src, buf.spare_capacity_mut().as_out(), check)?;
+    unsafe { buf.set_len(m) };
+    Ok(buf)
+}
@@ -0,0 +1,57 @@
+use crate::decode::decoding_table;
+
+use vsimd::alsw::AlswLut;
+use vsimd::base32::EncodingLutX2;
+use vsimd::vector::V128;
+
+/// Tables of a custom charset, used by [`Base32::custom`](crate::Base32::custom).
+///
+/// The tables take several hundred bytes, so a [`Base32`](crate::Base32) only holds a reference to them.
+#[derive(Debug, Clone, Copy)]
+pub struct CustomCharset {
+    pub(crate) charset: [u8; 32],
+    pub(crate) table: [u8; 256],
+    pub(crate) encoding_lut: EncodingLutX2,
+    /// The check and decode luts, or `None` if the charset is not compatible with ALSW.
+    pub(crate) alsw: Option<(AlswLut<V128>, AlswLut<V128>)>,
+}
+
+impl CustomCharset {
+    /// Generates the tables of a charset.
+    ///
+    /// The tables are generated at compile time if this function is used in a const item.
+    ///
+    /// # Panics
+    /// This function panics if the charset contains non-ascii or duplicate characters.
+    #[inline]
+    #[must_use]
+    pub const fn new(charset: &[u8; 32]) -> Self {
+        let mut i = 0;
+        while i < 32 {
+            let c = charset[i];
+            assert!(c.is_ascii(), "the charset contains non-ascii characters");
+            let mut j = 0;
+            while j < i {
+                assert!(charset[j] != c, "the charset contains duplicate characters");
+                j += 1;
+            }
+            i += 1;
+        }
+
+        let table = decoding_table(charset);
+        let alsw = vsimd::alsw::search(&table);
+        Self {
+            charset: *charset,
+            table,
+            encoding_lut: EncodingLutX2::new(charset),
+            alsw,
+        }
+    }
+
+    /// Returns the character set.
+    #[inline]
+    #[must_use]
+    pub const fn charset(&self) -> &[u8; 32] {
+        &self.charset
+    }
+}
@@ -1,19 +1,22 @@
-use crate::alsw::{BASE32HEX_ALSW_CHECK_X2, BASE32HEX_ALSW_DECODE_X2};
-use crate::alsw::{BASE32_ALSW_CHECK_X2, BASE32_ALSW_DECODE_X2};
-use crate::{u16x4_to_u64, Error, Kind};
-use crate::{BASE32HEX_CHARSET, BASE32_CHARSET};
+use crate::lookup::{LookupLutX2, GEOHASH_LOOKUP_X2, ZBASE32_LOOKUP_X2};
+use crate::{Error, Kind};
+use crate::{BASE32HEX_CHARSET, BASE32_CHARSET, CROCKFORD_CHARSET, GEOHASH_CHARSET, ZBASE32_CHARSET};
 
-use vsimd::alsw::AlswLut;
-use vsimd::isa::{AVX2, NEON, SSE41, WASM128};
+use vsimd::base32::{crockford_fold, decode_ascii32, merge_bits};
+use vsimd::base32::{BASE32HEX_ALSW_CHECK_X2, BASE32HEX_ALSW_DECODE_X2};
+use vsimd::base32::{BASE32HEX_CASE_INSENSITIVE_ALSW_CHECK_X2, BASE32HEX_CASE_INSENSITIVE_ALSW_DECODE_X2};
+use vsimd::base32::{BASE32_ALSW_CHECK_X2, BASE32_ALSW_DECODE_X2};
+use vsimd::base32::{BASE32_CASE_INSENSITIVE_ALSW_CHECK_X2, BASE32_CASE_INSENSITIVE_ALSW_DECODE_X2};
+use vsimd::base32::{CROCKFORD_ALSW_CHECK_X2, CROCKFORD_ALSW_DECODE_X2};
 use vsimd::mask::u8x32_highbit_any;
 use vsimd::tools::{read, write};
 use vsimd::vector::V256;
-use vsimd::{matches_isa, simd256_vop, SIMD256};
+use vsimd::SIMD256;
 
 use core::ops::Not;
 
 #[inline]
-const fn decoding_table(charset: &[u8; 32]) -> [u8; 256] {
+pub(crate) const fn decoding_table(charset: &[u8; 32]) -> [u8; 256] {
     let mut table = [0xff; 256];
     let mut i = 0;
     while i < 32 {
@@ -25,6 +28,29 @@ const fn decoding_table(charset: &[u8; 32]) -> [u8; 256] {
 
 pub const BASE32_TABLE: &[u8; 256] = &decoding_table(BASE32_CHARSET);
 pub const BASE32HEX_TABLE: &[u8; 256] = &decoding_table(BASE32HEX_CHARSET);
+pub const ZBASE32_TABLE: &[u8; 256] = &decoding_table(ZBASE32_CHARSET);
+pub const GEOHASH_TABLE: &[u8; 256] = &decoding_table(GEOHASH_CHARSET);
+
+pub const CROCKFORD_TABLE: &[u8; 256] = &{
+    let mut table = decoding_table(CROCKFORD_CHARSET);
+    table[b'I' as usize] = 1;
+    table[b'L' as usize] = 1;
+    table[b'O' as usize] = 0;
+    case_insensitive_table(table)
+};
+
+#[inline]
+const fn case_insensitive_table(mut table: [u8; 256]) -> [u8; 256] {
+    let mut c = b'A';
+    while c <= b'Z' {
+        table[c.to_ascii_lowercase() as usize] = table[c as usize];
+        c += 1;
+    }
+    table
+}
+
+pub const BASE32_CASE_INSENSITIVE_TABLE: &[u8; 256] = &case_insensitive_table(decoding_table(BASE32_CHARSET));
+pub const BASE32HEX_CASE_INSENSITIVE_TABLE: &[u8; 256] = &case_insensitive_table(decoding_table(BASE32HEX_CHARSET));
 
 #[inline]
 pub fn decoded_length(data: &[u8], padding: bool) -> Result<(usize, usize), Error> {
@@ -42,11 +68,16 @@ pub fn decoded_length(data: &[u8], padding: bool) -> Result<(usize, usize), Erro
         data.len()
     };
 
+    let m = unpadded_decoded_length(n)?;
+    Ok((n, m))
+}
+
+#[inline]
+pub fn unpadded_decoded_length(n: usize) -> Result<usize, Error> {
     const EXTRA: [u8; 8] = [0, 0xff, 1, 0xff, 2, 3, 0xff, 4];
     let extra = EXTRA[n % 8];
     ensure!(extra != 0xff);
-    let m = n / 8 * 5 + extra as usize;
-    Ok((n, m))
+    Ok(n / 8 * 5 + extra as usize)
 }
 
 #[inline(always)]
@@ -141,11 +172,17 @@ pub(crate) unsafe fn decode_fallback(
     mut src: *const u8,
     mut n: usize,
     mut dst: *mut u8,
-    kind: Kind,
+    kind: &Kind,
 ) -> Result<(), Error> {
     let table = match kind {
         Kind::Base32 => BASE32_TABLE.as_ptr(),
         Kind::Base32Hex => BASE32HEX_TABLE.as_ptr(),
+        Kind::Base32CaseInsensitive => BASE32_CASE_INSENSITIVE_TABLE.as_ptr(),
+        Kind::Base32HexCaseInsensitive => BASE32HEX_CASE_INSENSITIVE_TABLE.as_ptr(),
+        Kind::Crockford => CROCKFORD_TABLE.as_ptr(),
+        Kind::ZBase32 => ZBASE32_TABLE.as_ptr(),
+        Kind::Geohash => GEOHASH_TABLE.as_ptr(),
+        Kind::Custom(custom) => custom.table.as_ptr(),
     };
 
     let end = src.add(n / 8 * 8);
@@ -167,16 +204,35 @@ pub(crate) unsafe fn decode_simd<S: SIMD256>(
     mut src: *const u8,
     mut n: usize,
     mut dst: *mut u8,
-    kind: Kind,
+    kind: &Kind,
 ) -> Result<(), Error> {
     let (check_lut, decode_lut) = match kind {
         Kind::Base32 => (BASE32_ALSW_CHECK_X2, BASE32_ALSW_DECODE_X2),
         Kind::Base32Hex => (BASE32HEX_ALSW_CHECK_X2, BASE32HEX_ALSW_DECODE_X2),
+        Kind::Base32CaseInsensitive => (
+            BASE32_CASE_INSENSITIVE_ALSW_CHECK_X2,
+            BASE32_CASE_INSENSITIVE_ALSW_DECODE_X2,
+        ),
+        Kind::Base32HexCaseInsensitive => (
+            BASE32HEX_CASE_INSENSITIVE_ALSW_CHECK_X2,
+            BASE32HEX_CASE_INSENSITIVE_ALSW_DECODE_X2,
+        ),
+        Kind::Crockford => (CROCKFORD_ALSW_CHECK_X2, CROCKFORD_ALSW_DECODE_X2),
+        Kind::ZBase32 => return decode_lookup_simd(s, src, n, dst, kind, &ZBASE32_LOOKUP_X2),
+        Kind::Geohash => return decode_lookup_simd(s, src, n, dst, kind, &GEOHASH_LOOKUP_X2),
+        Kind::Custom(custom) => match custom.alsw {
+            Some((check_lut, decode_lut)) => (check_lut.x2(), decode_lut.x2()),
+            None => return decode_fallback(src, n, dst, kind),
+        },
     };
+    let fold = matches!(kind, Kind::Crockford);
 
     // n*5/8 >= 10+10+6
     while n >= 42 {
-        let x = s.v256_load_unaligned(src);
+        let mut x = s.v256_load_unaligned(src);
+        if fold {
+            x = crockford_fold(s, x);
+        }
         let y = try_!(decode_ascii32(s, x, check_lut, decode_lut));
 
         let (y1, y2) = y.to_v128x2();
@@ -192,82 +248,56 @@ pub(crate) unsafe fn decode_simd<S: SIMD256>(
 }
 
 #[inline(always)]
-fn u32x8_blend_0x55<S: SIMD256>(s: S, a: V256, b: V256) -> V256 {
-    if matches_isa!(S, AVX2) {
-        return s.u32x8_blend::<0x55>(a, b);
-    }
-    if matches_isa!(S, SSE41) {
-        return simd256_vop!(s, S::u16x8_blend::<0x33>, a, b);
-    }
-    unreachable!()
-}
+unsafe fn decode_lookup_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    mut n: usize,
+    mut dst: *mut u8,
+    kind: &Kind,
+    lut: &LookupLutX2,
+) -> Result<(), Error> {
+    // n*5/8 >= 10+10+6
+    while n >= 42 {
+        let x = s.v256_load_unaligned(src);
+        let y = try_!(decode_lookup32(s, x, lut));
 
-#[inline(always)]
-fn merge_bits<S: SIMD256>(s: S, x: V256) -> V256 {
-    if matches_isa!(S, SSE41) {
-        const MERGE_M1: u32 = u32::from_le_bytes([1 << 7, 1 << 2, 1 << 5, 1 << 0]);
-        const MERGE_S1: V256 = V256::double_bytes([
-            0x01, 0x00, 0x02, 0x04, 0x06, //
-            0x09, 0x08, 0x0A, 0x0C, 0x0E, //
-            0x80, 0x80, 0x80, 0x80, 0x80, 0x80, //
-        ]);
-        const MERGE_S2: V256 = V256::double_bytes([
-            0x80, 0x03, 0x05, 0x07, 0x80, //
-            0x80, 0x0B, 0x0D, 0x0F, 0x80, //
-            0x80, 0x80, 0x80, 0x80, 0x80, 0x80, //
-        ]);
-
-        let x1 = s.i16x16_maddubs(s.u32x8_splat(MERGE_M1), x);
-        let x2 = s.u32x8_shl::<4>(x1);
-        let x3 = u32x8_blend_0x55(s, x1, x2);
-        let x4 = s.u8x16x2_swizzle(x3, MERGE_S1);
-        let x5 = s.u8x16x2_swizzle(x3, MERGE_S2);
-        return s.v256_or(x4, x5);
-    }
+        let (y1, y2) = y.to_v128x2();
+        s.v128_store_unaligned(dst, y1);
+        s.v128_store_unaligned(dst.add(10), y2);
 
-    if matches_isa!(S, NEON | WASM128) {
-        const MERGE_M1: u16 = u16::from_le_bytes([0x1f, 0x00]);
-        const MERGE_M2: u64 = u16x4_to_u64([1 << 3, 1 << 1, 1 << 7, 1 << 5]);
-        const MERGE_M3: u64 = u16x4_to_u64([1 << 6, 1 << 4, 1 << 2, 1 << 0]);
-
-        const MERGE_S1: V256 = V256::double_bytes([
-            0x00, 0x02, 0x05, 0x07, 0x06, 0x80, 0x80, 0x04, //
-            0x08, 0x0A, 0x0D, 0x0F, 0x0E, 0x80, 0x80, 0x0C, //
-        ]);
-        const MERGE_S2: V256 = V256::double_bytes([
-            0x01, 0x00, 0x02, 0x04, 0x06, 0x03, 0x80, 0x80, //
-            0x09, 0x08, 0x0A, 0x0C, 0x0E, 0x0B, 0x80, 0x80, //
-        ]);
-        const MERGE_S3: V256 = V256::double_bytes([
-            0x00, 0x01, 0x02, 0x03, 0x04, //
-            0x08, 0x09, 0x0A, 0x0B, 0x0C, //
-            0x80, 0x80, 0x80, 0x80, 0x80, 0x80, //
-        ]);
-        const MERGE_S4: V256 = V256::double_bytes([
-            0x80, 0x05, 0x80, 0x07, 0x80, //
-            0x80, 0x0D, 0x80, 0x0F, 0x80, //
-            0x80, 0x80, 0x80, 0x80, 0x80, 0x80, //
-        ]);
-
-        let x1 = s.v256_and(x, s.u16x16_splat(MERGE_M1));
-        let x2 = s.i16x16_mul_lo(x1, s.u64x4_splat(MERGE_M2));
-        let x3 = s.u16x16_shr::<8>(x);
-        let x4 = s.i16x16_mul_lo(x3, s.u64x4_splat(MERGE_M3));
-        let x5 = s.u8x16x2_swizzle(x2, MERGE_S1);
-        let x6 = s.u8x16x2_swizzle(x4, MERGE_S2);
-        let x7 = s.v256_or(x5, x6);
-        let x8 = s.u8x16x2_swizzle(x7, MERGE_S3);
-        let x9 = s.u8x16x2_swizzle(x7, MERGE_S4);
-        return s.v256_or(x8, x9);
+        src = src.add(32);
+        dst = dst.add(20);
+        n -= 32;
     }
 
-    unreachable!()
+    decode_fallback(src, n, dst, kind)
 }
 
 #[inline(always)]
-fn decode_ascii32<S: SIMD256>(s: S, x: V256, check: AlswLut<V256>, decode: AlswLut<V256>) -> Result<V256, Error> {
-    let (c1, c2) = vsimd::alsw::decode_ascii_xn(s, x, check, decode);
-    let y = merge_bits(s, c2);
-    ensure!(u8x32_highbit_any(s, c1).not());
+fn decode_lookup32<S: SIMD256>(s: S, x: V256, lut: &LookupLutX2) -> Result<V256, Error> {
+    let c = crate::lookup::lookup_ascii32(s, x, lut);
+    let y = merge_bits(s, c);
+    ensure!(u8x32_highbit_any(s, c).not());
     Ok(y)
 }
+
+#[cfg(test)]
+mod algorithm {
+    use super::*;
+
+    #[cfg_attr(
+        any(miri, not(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))),
+        ignore
+    )]
+    #[test]
+    fn search() {
+        assert!(vsimd::alsw::search(BASE32_TABLE).is_some());
+        assert!(vsimd::alsw::search(BASE32HEX_TABLE).is_some());
+        assert!(vsimd::alsw::search(BASE32_CASE_INSENSITIVE_TABLE).is_some());
+        assert!(vsimd::alsw::search(ZBASE32_TABLE).is_none());
+
+        let mut table = *BASE32_TABLE;
+        table[0x80] = 0;
+        assert!(vsimd::alsw::search(&table).is_none());
+    }
+}
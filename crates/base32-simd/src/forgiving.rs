@@ -0,0 +1,167 @@
+use crate::BASE32_FORGIVING;
+use crate::{Error, Out};
+
+use vsimd::tools::slice_mut;
+
+use core::ptr::copy_nonoverlapping;
+
+#[cfg(all(feature = "alloc", not(any(test, feature = "std"))))]
+use alloc::vec::Vec;
+
+#[inline(always)]
+fn is_ignored(c: u8) -> bool {
+    c.is_ascii_whitespace() || c == b'-'
+}
+
+#[inline(always)]
+fn find_ignored(data: &[u8]) -> usize {
+    data.iter().position(|&c| is_ignored(c)).unwrap_or(data.len())
+}
+
+#[inline(always)]
+unsafe fn remove_ignored(mut src: *const u8, len: usize, mut dst: *mut u8) -> usize {
+    let dst_base = dst;
+
+    let end = src.add(len);
+    while src < end {
+        let x = src.read();
+        if !is_ignored(x) {
+            dst.write(x);
+            dst = dst.add(1);
+        }
+        src = src.add(1);
+    }
+
+    dst.offset_from(dst_base) as usize
+}
+
+/// Removes up to 6 trailing `=`.
+#[inline(always)]
+fn strip_padding(data: &mut [u8]) -> Result<&mut [u8], Error> {
+    let count = data.iter().rev().take_while(|&&c| c == b'=').count();
+    ensure!(count <= 6);
+    let len = data.len() - count;
+    Ok(&mut data[..len])
+}
+
+/// Forgiving decodes a base32 string to bytes and writes inplace.
+///
+/// This function uses the `Base32` charset.
+/// Ascii whitespace and hyphens are removed, the padding may be missing or partial,
+/// and lowercase characters are accepted.
+///
+/// # Errors
+/// This function returns `Err` if the content of `data` is invalid.
+#[inline]
+pub fn forgiving_decode_inplace(data: &mut [u8]) -> Result<&mut [u8], Error> {
+    let pos = find_ignored(data);
+    let data = if pos == data.len() {
+        data
+    } else {
+        unsafe {
+            let len = data.len() - pos;
+            let dst = data.as_mut_ptr().add(pos);
+            let rem = remove_ignored(dst, len, dst);
+            debug_assert!(rem <= len);
+            data.get_unchecked_mut(..(pos + rem))
+        }
+    };
+    BASE32_FORGIVING.decode_inplace(strip_padding(data)?)
+}
+
+/// Forgiving decodes a base32 string to bytes.
+///
+/// This function uses the `Base32` charset.
+/// Ascii whitespace and hyphens are removed, the padding may be missing or partial,
+/// and lowercase characters are accepted.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + `src.len() > dst.len()`
+/// + the content of `src` is invalid
+///
+#[inline]
+pub fn forgiving_decode<'d>(src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    ensure!(src.len() <= dst.len());
+
+    let pos = find_ignored(src);
+    debug_assert!(pos <= src.len());
+
+    unsafe {
+        let len = src.len();
+        let src = src.as_ptr();
+        let dst = dst.as_mut_ptr();
+
+        copy_nonoverlapping(src, dst, pos);
+
+        let rem = remove_ignored(src.add(pos), len - pos, dst.add(pos));
+        debug_assert!(rem <= len - pos);
+
+        let data = slice_mut(dst, pos + rem);
+        BASE32_FORGIVING.decode_inplace(strip_padding(data)?)
+    }
+}
+
+/// Forgiving decodes a base32 string to bytes and returns a new [`Vec<u8>`](Vec).
+///
+/// This function uses the `Base32` charset.
+/// Ascii whitespace and hyphens are removed, the padding may be missing or partial,
+/// and lowercase characters are accepted.
+///
+/// # Errors
+/// This function returns `Err` if the content of `data` is invalid.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn forgiving_decode_to_vec(data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut vec = data.to_vec();
+    let ans_len = forgiving_decode_inplace(&mut vec)?.len();
+    vec.truncate(ans_len);
+    Ok(vec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::AsOut;
+
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    fn test_forgiving() {
+        let cases: &[(&str, &[u8])] = &[
+            ("", b""),
+            ("MY", b"f"),
+            ("MY=", b"f"),
+            ("MY======", b"f"),
+            ("mzxw6", b"foo"),
+            ("MZXW6===", b"foo"),
+            ("mzxw 6ytb oi", b"foobar"),
+            ("MZXW-6YTB-OI==", b"foobar"),
+            ("jbsw y3dp ehpk 3pxp", b"Hello!\xde\xad\xbe\xef"),
+            (" JBSW\tY3DP\r\nEHPK-3PXP ", b"Hello!\xde\xad\xbe\xef"),
+        ];
+
+        for &(src, expected) in cases {
+            let mut buf = src.to_owned().into_bytes();
+
+            let ans = forgiving_decode_inplace(&mut buf).unwrap();
+            assert_eq!(ans, expected, "src = {src:?}");
+
+            let ans = crate::forgiving_decode(src.as_bytes(), buf.as_out()).unwrap();
+            assert_eq!(ans, expected, "src = {src:?}");
+
+            #[cfg(feature = "alloc")]
+            {
+                let ans = crate::forgiving_decode_to_vec(src.as_bytes()).unwrap();
+                assert_eq!(ans, expected, "src = {src:?}");
+            }
+        }
+
+        let invalid = ["M", "MY=======", "MY=A", "MY_", "MZXW6YTB1", "M=Y"];
+        for src in invalid {
+            let mut buf = src.to_owned().into_bytes();
+            assert!(forgiving_decode_inplace(&mut buf).is_err(), "src = {src:?}");
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Geohash strings as integers.
+//!
+//! [`GEOHASH`](crate::GEOHASH) treats a geohash as bytes, which only works for lengths that are a whole number of bytes.
+//! The functions in this module work on the bit length of the hash instead:
+//! a geohash of `n` characters is the integer formed by its `5 * n` bits, with the first character as the most significant symbol.
+//! A `u64` holds up to [`MAX_LEN`] characters.
+//!
+//! # Examples
+//!
+//! ```
+//! use base32_simd::geohash;
+//! use base32_simd::AsOut;
+//!
+//! let value = geohash::decode(b"u4pruydqqvj").unwrap();
+//! assert_eq!(value, 0x0068_95be_bccb_5b71);
+//!
+//! let mut buf = [0u8; geohash::MAX_LEN];
+//! let encoded = geohash::encode(value, 11, buf[..].as_out()).unwrap();
+//! assert_eq!(encoded, b"u4pruydqqvj");
+//! ```
+
+use crate::decode::GEOHASH_TABLE;
+use crate::{Error, Out, GEOHASH_CHARSET};
+
+use vsimd::tools::{read, slice_mut, write};
+
+#[cfg(all(feature = "alloc", not(any(test, feature = "std"))))]
+use alloc::string::String;
+
+/// The maximum length of a geohash that fits in a `u64`.
+pub const MAX_LEN: usize = 12;
+
+/// Decodes a geohash of at most [`MAX_LEN`] characters to its `5 * src.len()` bits.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `src` is greater than [`MAX_LEN`].
+/// + the content of `src` is invalid.
+#[inline]
+pub fn decode(src: &[u8]) -> Result<u64, Error> {
+    ensure!(src.len() <= MAX_LEN);
+    let mut value: u64 = 0;
+    let mut flag = 0;
+    for &c in src {
+        let x = unsafe { read(GEOHASH_TABLE.as_ptr(), c as usize) };
+        flag |= x;
+        value = value << 5 | (x & 0x1f) as u64;
+    }
+    ensure!(flag != 0xff);
+    Ok(value)
+}
+
+/// Encodes the low `5 * len` bits of `value` to a geohash of `len` characters.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + `len` is greater than [`MAX_LEN`].
+/// + `value` has bits set above the low `5 * len` bits.
+/// + the length of `dst` is not enough.
+#[inline]
+pub fn encode(value: u64, len: usize, mut dst: Out<'_, [u8]>) -> Result<&mut [u8], Error> {
+    ensure!(len <= MAX_LEN && value >> (5 * len) == 0);
+    ensure!(dst.len() >= len);
+    unsafe {
+        let dst = dst.as_mut_ptr();
+        let mut value = value;
+        for i in (0..len).rev() {
+            write(dst, i, read(GEOHASH_CHARSET.as_ptr(), (value & 0x1f) as usize));
+            value >>= 5;
+        }
+        Ok(slice_mut(dst, len))
+    }
+}
+
+/// Encodes the low `5 * len` bits of `value` to a geohash of `len` characters.
+///
+/// # Panics
+/// This function panics if `len` is greater than [`MAX_LEN`]
+/// or `value` has bits set above the low `5 * len` bits.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[inline]
+#[must_use]
+pub fn encode_to_string(value: u64, len: usize) -> String {
+    use crate::AsOut;
+
+    let mut buf = [0u8; MAX_LEN];
+    let ans = encode(value, len, buf[..].as_out()).expect("the value does not fit in the geohash length");
+    unsafe { core::str::from_utf8_unchecked(ans) }.into()
+}
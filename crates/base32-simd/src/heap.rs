@@ -26,7 +26,7 @@ fn decode_to_boxed_bytes(base32: &Base32, src: &[u8]) -> Result<Box<[u8]>, Error
         {
             let src: *const u8 = src.as_ptr();
             let dst: *mut u8 = buf.as_mut_ptr().cast();
-            crate::multiversion::decode::auto(src, n, dst, base32.kind)?;
+            crate::multiversion::decode::auto(src, n, dst, &base32.kind)?;
         }
 
         Ok(assume_init(buf))
@@ -47,7 +47,7 @@ fn decode_append_vec(base32: &Base32, src: &[u8], buf: &mut Vec<u8>) -> Result<(
     unsafe {
         let dst: *mut u8 = buf.as_mut_ptr().add(prev_len);
         let src: *const u8 = src.as_ptr();
-        crate::multiversion::decode::auto(src, n, dst, base32.kind)?;
+        crate::multiversion::decode::auto(src, n, dst, &base32.kind)?;
 
         buf.set_len(prev_len + m);
         Ok(())
@@ -69,7 +69,7 @@ fn encode_to_boxed_str(base32: &Base32, src: &[u8]) -> Box<str> {
         {
             let (src, len) = slice_parts(src);
             let dst: *mut u8 = buf.as_mut_ptr().cast();
-            crate::multiversion::encode::auto(src, len, dst, base32.kind, base32.padding);
+            crate::multiversion::encode::auto(src, len, dst, &base32.kind, base32.padding);
         }
 
         boxed_str(assume_init(buf))
@@ -91,7 +91,7 @@ fn encode_append_vec(base32: &Base32, src: &[u8], buf: &mut Vec<u8>) {
     unsafe {
         let (src, len) = slice_parts(src);
         let dst = buf.as_mut_ptr().add(prev_len);
-        crate::multiversion::encode::auto(src, len, dst, base32.kind, base32.padding);
+        crate::multiversion::encode::auto(src, len, dst, &base32.kind, base32.padding);
 
         buf.set_len(prev_len + m);
     }
@@ -51,16 +51,30 @@ extern crate alloc;
 mod error;
 pub use self::error::Error;
 
-mod alsw;
 mod check;
 mod decode;
 mod encode;
+mod lookup;
 
 mod multiversion;
 
 #[cfg(feature = "alloc")]
 mod heap;
 
+#[cfg(feature = "parallel")]
+mod parallel;
+
+pub mod crockford;
+pub mod geohash;
+
+mod custom;
+pub use self::custom::CustomCharset;
+
+pub mod stream;
+
+mod forgiving;
+pub use self::forgiving::*;
+
 pub use outref::{AsOut, Out};
 
 // -----------------------------------------------------------------------------
@@ -75,11 +89,9 @@ use alloc::{string::String, vec::Vec};
 
 const BASE32_CHARSET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
 const BASE32HEX_CHARSET: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
-
-#[inline(always)]
-const fn u16x4_to_u64(x: [u16; 4]) -> u64 {
-    unsafe { core::mem::transmute(x) }
-}
+const CROCKFORD_CHARSET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const ZBASE32_CHARSET: &[u8; 32] = b"ybndrfg8ejkmcpqxot1uwisza345h769";
+const GEOHASH_CHARSET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
 
 /// Base32 variant
 #[derive(Debug)]
@@ -92,6 +104,12 @@ pub struct Base32 {
 pub(crate) enum Kind {
     Base32,
     Base32Hex,
+    Base32CaseInsensitive,
+    Base32HexCaseInsensitive,
+    Crockford,
+    ZBase32,
+    Geohash,
+    Custom(&'static CustomCharset),
 }
 
 /// `Base32` charset with padding.
@@ -118,17 +136,123 @@ pub const BASE32HEX_NO_PAD: Base32 = Base32 {
     padding: false,
 };
 
+/// [Crockford's Base32](https://www.crockford.com/base32.html) charset without padding.
+///
+/// Decoding is case-insensitive and maps `I` and `L` to `1`, `O` to `0`.
+/// See [`crockford`] for hyphens and check symbols.
+pub const CROCKFORD: Base32 = Base32 {
+    kind: Kind::Crockford,
+    padding: false,
+};
+
+const BASE32_FORGIVING: Base32 = BASE32_NO_PAD.case_insensitive();
+
+/// [z-base-32](https://philzimmermann.com/docs/human-oriented-base-32-encoding.txt) charset without padding.
+pub const ZBASE32: Base32 = Base32 {
+    kind: Kind::ZBase32,
+    padding: false,
+};
+
+/// [Geohash](https://en.wikipedia.org/wiki/Geohash) charset without padding.
+///
+/// The input is treated as bytes, so a geohash can only be decoded
+/// if its length in bits is a whole number of bytes with zero trailing bits.
+/// Use the [`geohash`] module to decode and encode geohashes of any length.
+pub const GEOHASH: Base32 = Base32 {
+    kind: Kind::Geohash,
+    padding: false,
+};
+
 impl Base32 {
     /// Returns the character set.
     #[inline]
     #[must_use]
     pub fn charset(&self) -> &'static [u8; 32] {
         match self.kind {
-            Kind::Base32 => BASE32_CHARSET,
-            Kind::Base32Hex => BASE32HEX_CHARSET,
+            Kind::Base32 | Kind::Base32CaseInsensitive => BASE32_CHARSET,
+            Kind::Base32Hex | Kind::Base32HexCaseInsensitive => BASE32HEX_CHARSET,
+            Kind::Crockford => CROCKFORD_CHARSET,
+            Kind::ZBase32 => ZBASE32_CHARSET,
+            Kind::Geohash => GEOHASH_CHARSET,
+            Kind::Custom(custom) => &custom.charset,
         }
     }
 
+    /// Returns the tables of a custom charset, or `None` for the built-in variants.
+    #[inline]
+    #[must_use]
+    pub fn custom_charset(&self) -> Option<&'static CustomCharset> {
+        match self.kind {
+            Kind::Custom(custom) => Some(custom),
+            _ => None,
+        }
+    }
+
+    /// Creates a variant with a custom charset.
+    ///
+    /// Checking and decoding are SIMD-accelerated if the charset is compatible with the ALSW algorithm,
+    /// otherwise they fall back to a scalar table.
+    ///
+    /// # Panics
+    /// This function panics if the charset contains `=` and `padding` is true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// use base32_simd::{Base32, CustomCharset};
+    ///
+    /// const LOWER: Base32 = Base32::custom(&CustomCharset::new(b"abcdefghijklmnopqrstuvwxyz234567"), false);
+    ///
+    /// let encoded = LOWER.encode_to_string(b"hello");
+    /// assert_eq!(encoded, "nbswy3dp");
+    /// assert_eq!(LOWER.decode_to_vec(encoded).unwrap(), b"hello");
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn custom(charset: &'static CustomCharset, padding: bool) -> Self {
+        if padding {
+            let mut i = 0;
+            while i < 32 {
+                assert!(charset.charset[i] != b'=', "the charset conflicts with padding");
+                i += 1;
+            }
+        }
+        Self {
+            kind: Kind::Custom(charset),
+            padding,
+        }
+    }
+
+    /// Returns a variant which also accepts lowercase characters when checking and decoding.
+    ///
+    /// Encoding still produces uppercase characters.
+    /// This function only affects the `Base32` and `Base32Hex` charsets.
+    /// [`CROCKFORD`] is always case-insensitive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")]
+    /// # {
+    /// let base32 = base32_simd::BASE32_NO_PAD.case_insensitive();
+    /// assert_eq!(base32.decode_to_vec("nbswy3dp").unwrap(), b"hello");
+    /// assert_eq!(base32.decode_to_vec("NBSWY3DP").unwrap(), b"hello");
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn case_insensitive(self) -> Self {
+        let kind = match self.kind {
+            Kind::Base32 => Kind::Base32CaseInsensitive,
+            Kind::Base32Hex => Kind::Base32HexCaseInsensitive,
+            kind => kind,
+        };
+        Self { kind, ..self }
+    }
+
     /// Calculates the encoded length.
     ///
     /// # Panics
@@ -173,7 +297,7 @@ impl Base32 {
     pub fn check(&self, data: &[u8]) -> Result<(), Error> {
         let (n, _) = decoded_length(data, self.padding)?;
         let src = data.as_ptr();
-        unsafe { crate::multiversion::check::auto(src, n, self.kind) }
+        unsafe { crate::multiversion::check::auto(src, n, &self.kind) }
     }
 
     /// Encodes bytes to a base32 string.
@@ -188,7 +312,7 @@ impl Base32 {
 
             let (src, len) = slice_parts(src);
             let dst = dst.as_mut_ptr();
-            self::multiversion::encode::auto(src, len, dst, self.kind, self.padding);
+            self::multiversion::encode::auto(src, len, dst, &self.kind, self.padding);
 
             Ok(slice_mut(dst, m))
         }
@@ -218,7 +342,7 @@ impl Base32 {
 
             let src = src.as_ptr();
             let dst = dst.as_mut_ptr();
-            self::multiversion::decode::auto(src, n, dst, self.kind)?;
+            self::multiversion::decode::auto(src, n, dst, &self.kind)?;
 
             Ok(slice_mut(dst, m))
         }
@@ -235,7 +359,7 @@ impl Base32 {
 
             let dst: *mut u8 = data.as_mut_ptr();
             let src: *const u8 = dst;
-            crate::multiversion::decode::auto(src, n, dst, self.kind)?;
+            crate::multiversion::decode::auto(src, n, dst, &self.kind)?;
 
             Ok(slice_mut(dst, m))
         }
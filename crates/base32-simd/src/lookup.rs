@@ -0,0 +1,53 @@
+use crate::decode::{GEOHASH_TABLE, ZBASE32_TABLE};
+
+use vsimd::vector::V256;
+use vsimd::SIMD256;
+
+const FIRST_ROW: usize = 2;
+const ROWS: usize = 6;
+
+/// Decoding table of printable ascii characters (`0x20..=0x7F`), stored in rows of 16 bytes.
+///
+/// Alphabets that can not be handled by ALSW are decoded with this table.
+#[derive(Debug, Clone, Copy)]
+pub struct LookupLutX2 {
+    rows: [V256; ROWS],
+}
+
+impl LookupLutX2 {
+    pub const fn new(table: &[u8; 256]) -> Self {
+        let mut rows = [V256::from_bytes([0; 32]); ROWS];
+        let mut i = 0;
+        while i < ROWS {
+            let mut row = [0; 16];
+            let mut j = 0;
+            while j < 16 {
+                row[j] = table[(FIRST_ROW + i) * 16 + j];
+                j += 1;
+            }
+            rows[i] = V256::double_bytes(row);
+            i += 1;
+        }
+        Self { rows }
+    }
+}
+
+pub const ZBASE32_LOOKUP_X2: LookupLutX2 = LookupLutX2::new(ZBASE32_TABLE);
+pub const GEOHASH_LOOKUP_X2: LookupLutX2 = LookupLutX2::new(GEOHASH_TABLE);
+
+/// Looks up the values of characters. Invalid characters are mapped to `0xff`.
+#[inline(always)]
+pub fn lookup_ascii32<S: SIMD256>(s: S, x: V256, lut: &LookupLutX2) -> V256 {
+    let lo = s.v256_and(x, s.u8x32_splat(0x0f));
+    let hi = s.v256_and(s.u16x16_shr::<4>(x), s.u8x32_splat(0x0f));
+
+    let mut y = s.u8x32_splat(0xff);
+    let mut i = 0;
+    while i < ROWS {
+        let is_row = s.u8x32_eq(hi, s.u8x32_splat((FIRST_ROW + i) as u8));
+        let values = s.u8x16x2_swizzle(lut.rows[i], lo);
+        y = s.v256_andnot(y, s.v256_andnot(is_row, values));
+        i += 1;
+    }
+    y
+}
@@ -0,0 +1,114 @@
+use crate::decode::decoded_length;
+use crate::encode::encoded_length_unchecked;
+use crate::{Base32, Error, Out};
+
+use rayon::prelude::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+use vsimd::tools::slice_mut;
+
+/// Returns the number of groups per chunk, or `None` if `len` is too short to be worth splitting.
+#[inline(always)]
+fn chunk_groups(len: usize, group: usize) -> Option<usize> {
+    let p = rayon::current_num_threads();
+    if len < p * 4096 || p < 2 {
+        return None;
+    }
+    Some((len / group).div_ceil(p))
+}
+
+impl Base32 {
+    /// **EXPERIMENTAL**:
+    /// Encodes bytes to a base32 string in parallel.
+    ///
+    /// # Errors
+    /// This function returns `Err` if the length of `dst` is not enough.
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    #[inline]
+    pub fn par_encode<'d>(&self, src: &[u8], dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+        let Some(k) = chunk_groups(src.len(), 5) else {
+            return self.encode(src, dst);
+        };
+
+        let m = encoded_length_unchecked(src.len(), self.padding);
+        ensure!(dst.len() >= m);
+
+        let dst = unsafe { dst.into_uninit_slice() };
+        let dst = &mut dst[..m];
+
+        let src_chunks = src.par_chunks(k * 5);
+        let dst_chunks = dst.par_chunks_mut(k * 8);
+
+        // only the tail chunk can be incomplete
+        src_chunks.zip(dst_chunks).for_each(|(s, d)| unsafe {
+            let len = s.len();
+            let sp = s.as_ptr();
+            let dp = d.as_mut_ptr().cast::<u8>();
+            let padding = self.padding && len % 5 != 0;
+            crate::multiversion::encode::auto(sp, len, dp, &self.kind, padding);
+        });
+
+        unsafe {
+            let len = dst.len();
+            let ptr = dst.as_mut_ptr().cast::<u8>();
+            Ok(slice_mut(ptr, len))
+        }
+    }
+
+    /// **EXPERIMENTAL**:
+    /// Decodes a base32 string to bytes in parallel.
+    ///
+    /// # Errors
+    /// This function returns `Err` if
+    /// + the length of `dst` is not enough.
+    /// + the content of `src` is invalid.
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    #[inline]
+    pub fn par_decode<'d>(&self, src: &[u8], dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+        let Some(k) = chunk_groups(src.len(), 8) else {
+            return self.decode(src, dst);
+        };
+
+        let (n, m) = decoded_length(src, self.padding)?;
+        ensure!(dst.len() >= m);
+
+        let dst = unsafe { dst.into_uninit_slice() };
+        let dst = &mut dst[..m];
+
+        let src_chunks = src[..n].par_chunks(k * 8);
+        let dst_chunks = dst.par_chunks_mut(k * 5);
+
+        src_chunks.zip(dst_chunks).try_for_each(|(s, d)| unsafe {
+            let len = s.len();
+            let sp = s.as_ptr();
+            let dp = d.as_mut_ptr().cast::<u8>();
+            crate::multiversion::decode::auto(sp, len, dp, &self.kind)
+        })?;
+
+        unsafe {
+            let len = dst.len();
+            let ptr = dst.as_mut_ptr().cast::<u8>();
+            Ok(slice_mut(ptr, len))
+        }
+    }
+
+    /// **EXPERIMENTAL**:
+    /// Checks whether `data` is a base32 string in parallel.
+    ///
+    /// # Errors
+    /// This function returns `Err` if the content of `data` is invalid.
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    #[inline]
+    pub fn par_check(&self, data: &[u8]) -> Result<(), Error> {
+        let Some(k) = chunk_groups(data.len(), 8) else {
+            return self.check(data);
+        };
+
+        let (n, _) = decoded_length(data, self.padding)?;
+
+        data[..n].par_chunks(k * 8).try_for_each(|s| unsafe {
+            let len = s.len();
+            let sp = s.as_ptr();
+            crate::multiversion::check::auto(sp, len, &self.kind)
+        })
+    }
+}
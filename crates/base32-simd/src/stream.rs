@@ -0,0 +1,406 @@
+//! Streaming base32 encoding and decoding.
+//!
+//! [`Encoder`] and [`Decoder`] process the input in pieces and keep the incomplete
+//! group (up to 5 bytes or 8 characters) between calls.
+//! The padding is only written or accepted by `finish`.
+//!
+//! With the `std` feature, [`EncoderWriter`] and [`DecoderReader`] adapt them to
+//! [`io::Write`](std::io::Write) and [`io::Read`](std::io::Read).
+//!
+//! # Examples
+//!
+//! ```
+//! use base32_simd::stream::{Decoder, Encoder};
+//! use base32_simd::{AsOut, BASE32};
+//!
+//! let mut buf = [0u8; 16];
+//!
+//! let mut encoder = Encoder::new(&BASE32);
+//! assert_eq!(encoder.encode(b"foo", buf[..].as_out()).unwrap(), b"");
+//! assert_eq!(encoder.encode(b"bar", buf[..].as_out()).unwrap(), b"MZXW6YTB");
+//! assert_eq!(encoder.finish(buf[..].as_out()).unwrap(), b"OI======");
+//!
+//! let mut decoder = Decoder::new(&BASE32);
+//! assert_eq!(decoder.decode(b"MZXW6YTBO", buf[..].as_out()).unwrap(), b"fooba");
+//! assert_eq!(decoder.decode(b"I======", buf[..].as_out()).unwrap(), b"");
+//! assert_eq!(decoder.finish(buf[..].as_out()).unwrap(), b"r");
+//! ```
+
+use crate::{Base32, Error, Out};
+
+use vsimd::tools::slice_mut;
+
+#[cfg(feature = "std")]
+use crate::AsOut;
+
+#[cfg(feature = "std")]
+use std::{fmt, io};
+
+/// A stateful base32 encoder.
+#[derive(Debug, Clone)]
+pub struct Encoder<'a> {
+    base32: &'a Base32,
+    buf: [u8; 5],
+    len: usize,
+}
+
+impl<'a> Encoder<'a> {
+    /// Creates an encoder of the `base32` variant.
+    #[inline]
+    #[must_use]
+    pub const fn new(base32: &'a Base32) -> Self {
+        Self {
+            base32,
+            buf: [0; 5],
+            len: 0,
+        }
+    }
+
+    /// Calculates the output length of [`Encoder::encode`] with `n` more bytes.
+    #[inline]
+    #[must_use]
+    pub const fn output_length(&self, n: usize) -> usize {
+        (self.len + n) / 5 * 8
+    }
+
+    /// Encodes the complete 5-byte groups and keeps the remaining bytes.
+    ///
+    /// # Errors
+    /// This function returns `Err` if the length of `dst` is less than [`Encoder::output_length`].
+    #[inline]
+    pub fn encode<'d>(&mut self, mut src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+        let m = self.output_length(src.len());
+        ensure!(dst.len() >= m);
+
+        let kind = &self.base32.kind;
+        unsafe {
+            let dst = dst.as_mut_ptr();
+            let mut pos = 0;
+
+            if self.len > 0 {
+                let k = (5 - self.len).min(src.len());
+                self.buf[self.len..self.len + k].copy_from_slice(&src[..k]);
+                self.len += k;
+                src = &src[k..];
+
+                if self.len < 5 {
+                    return Ok(slice_mut(dst, 0));
+                }
+                crate::multiversion::encode::auto(self.buf.as_ptr(), 5, dst, kind, false);
+                self.len = 0;
+                pos = 8;
+            }
+
+            let n = src.len() / 5 * 5;
+            crate::multiversion::encode::auto(src.as_ptr(), n, dst.add(pos), kind, false);
+
+            let rest = &src[n..];
+            self.buf[..rest.len()].copy_from_slice(rest);
+            self.len = rest.len();
+
+            debug_assert_eq!(pos + n / 5 * 8, m);
+            Ok(slice_mut(dst, m))
+        }
+    }
+
+    /// Encodes the remaining bytes, with padding if the variant requires it.
+    ///
+    /// The output is at most 8 bytes.
+    ///
+    /// # Errors
+    /// This function returns `Err` if the length of `dst` is not enough.
+    #[inline]
+    pub fn finish(self, dst: Out<'_, [u8]>) -> Result<&mut [u8], Error> {
+        self.base32.encode(&self.buf[..self.len], dst)
+    }
+}
+
+/// A stateful base32 decoder.
+///
+/// The last group is kept until [`Decoder::finish`] because it may contain padding.
+/// The decoder should be discarded after an error.
+#[derive(Debug, Clone)]
+pub struct Decoder<'a> {
+    base32: &'a Base32,
+    buf: [u8; 8],
+    len: usize,
+}
+
+impl<'a> Decoder<'a> {
+    /// Creates a decoder of the `base32` variant.
+    #[inline]
+    #[must_use]
+    pub const fn new(base32: &'a Base32) -> Self {
+        Self {
+            base32,
+            buf: [0; 8],
+            len: 0,
+        }
+    }
+
+    /// Calculates the output length of [`Decoder::decode`] with `n` more characters.
+    #[inline]
+    #[must_use]
+    pub const fn output_length(&self, n: usize) -> usize {
+        let total = self.len + n;
+        if total == 0 {
+            0
+        } else {
+            (total - 1) / 8 * 5
+        }
+    }
+
+    /// Decodes the complete 8-character groups except the last one, and keeps the remaining characters.
+    ///
+    /// # Errors
+    /// This function returns `Err` if
+    /// + the length of `dst` is less than [`Decoder::output_length`].
+    /// + the content of `src` is invalid.
+    #[inline]
+    pub fn decode<'d>(&mut self, mut src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+        let m = self.output_length(src.len());
+        ensure!(dst.len() >= m);
+
+        let kind = &self.base32.kind;
+        unsafe {
+            let dst = dst.as_mut_ptr();
+
+            if m == 0 {
+                self.buf[self.len..self.len + src.len()].copy_from_slice(src);
+                self.len += src.len();
+                return Ok(slice_mut(dst, 0));
+            }
+
+            let mut pos = 0;
+            if self.len > 0 {
+                let k = 8 - self.len;
+                self.buf[self.len..].copy_from_slice(&src[..k]);
+                src = &src[k..];
+                self.len = 0;
+
+                crate::multiversion::decode::auto(self.buf.as_ptr(), 8, dst, kind)?;
+                pos = 5;
+            }
+
+            // hold back 1..=8 characters
+            let n = (src.len() - 1) / 8 * 8;
+            crate::multiversion::decode::auto(src.as_ptr(), n, dst.add(pos), kind)?;
+
+            let rest = &src[n..];
+            self.buf[..rest.len()].copy_from_slice(rest);
+            self.len = rest.len();
+
+            debug_assert_eq!(pos + n / 8 * 5, m);
+            Ok(slice_mut(dst, m))
+        }
+    }
+
+    /// Decodes the remaining characters, with padding if the variant requires it.
+    ///
+    /// The output is at most 5 bytes.
+    ///
+    /// # Errors
+    /// This function returns `Err` if
+    /// + the length of `dst` is not enough.
+    /// + the content of the remaining characters is invalid.
+    #[inline]
+    pub fn finish(self, dst: Out<'_, [u8]>) -> Result<&mut [u8], Error> {
+        self.base32.decode(&self.buf[..self.len], dst)
+    }
+}
+
+#[cfg(feature = "std")]
+const ENCODE_CHUNK: usize = 1020;
+
+#[cfg(feature = "std")]
+const DECODE_CHUNK: usize = 1024;
+
+/// An [`io::Write`] adapter which encodes the written bytes into `W`.
+///
+/// [`EncoderWriter::finish`] must be called to write the last group.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub struct EncoderWriter<'a, W> {
+    encoder: Encoder<'a>,
+    inner: W,
+    buf: [u8; ENCODE_CHUNK.div_ceil(5) * 8],
+}
+
+#[cfg(feature = "std")]
+impl<'a, W: io::Write> EncoderWriter<'a, W> {
+    /// Creates an encoding writer of the `base32` variant.
+    #[inline]
+    #[must_use]
+    pub fn new(base32: &'a Base32, inner: W) -> Self {
+        Self {
+            encoder: Encoder::new(base32),
+            inner,
+            buf: [0; ENCODE_CHUNK.div_ceil(5) * 8],
+        }
+    }
+
+    /// Gets a reference to the underlying writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Writes the last group, flushes and returns the underlying writer.
+    ///
+    /// # Errors
+    /// This function returns `Err` if the underlying writer fails.
+    #[inline]
+    pub fn finish(mut self) -> io::Result<W> {
+        let ans = self.encoder.finish(self.buf[..].as_out()).map_err(invalid_data)?;
+        self.inner.write_all(ans)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> io::Write for EncoderWriter<'_, W> {
+    #[inline]
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        let n = data.len().min(ENCODE_CHUNK);
+        let ans = self
+            .encoder
+            .encode(&data[..n], self.buf[..].as_out())
+            .map_err(invalid_data)?;
+        self.inner.write_all(ans)?;
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// An [`io::Read`] adapter which decodes the bytes read from `R`.
+///
+/// Invalid input is reported as [`io::ErrorKind::InvalidData`],
+/// and all later reads fail with the same kind of error.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+pub struct DecoderReader<'a, R> {
+    decoder: Option<Decoder<'a>>,
+    failed: bool,
+    inner: R,
+    input: [u8; DECODE_CHUNK],
+    output: [u8; DECODE_CHUNK.div_ceil(8) * 5],
+    pos: usize,
+    len: usize,
+}
+
+#[cfg(feature = "std")]
+impl<'a, R: io::Read> DecoderReader<'a, R> {
+    /// Creates a decoding reader of the `base32` variant.
+    #[inline]
+    #[must_use]
+    pub fn new(base32: &'a Base32, inner: R) -> Self {
+        Self {
+            decoder: Some(Decoder::new(base32)),
+            failed: false,
+            inner,
+            input: [0; DECODE_CHUNK],
+            output: [0; DECODE_CHUNK.div_ceil(8) * 5],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Gets a reference to the underlying reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> io::Read for DecoderReader<'_, R> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        loop {
+            if self.pos < self.len {
+                let n = buf.len().min(self.len - self.pos);
+                buf[..n].copy_from_slice(&self.output[self.pos..self.pos + n]);
+                self.pos += n;
+                return Ok(n);
+            }
+
+            let Some(decoder) = self.decoder.as_mut() else {
+                if self.failed {
+                    return Err(invalid_data(Error::new()));
+                }
+                return Ok(0);
+            };
+
+            let n = self.inner.read(&mut self.input)?;
+            let ans = if n == 0 {
+                let decoder = self.decoder.take().unwrap();
+                decoder.finish(self.output[..].as_out())
+            } else {
+                decoder.decode(&self.input[..n], self.output[..].as_out())
+            };
+            match ans {
+                Ok(ans) => self.len = ans.len(),
+                Err(err) => {
+                    self.decoder = None;
+                    self.failed = true;
+                    return Err(invalid_data(err));
+                }
+            }
+            self.pos = 0;
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: fmt::Debug> fmt::Debug for EncoderWriter<'_, W> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncoderWriter")
+            .field("encoder", &self.encoder)
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: fmt::Debug> fmt::Debug for DecoderReader<'_, R> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecoderReader")
+            .field("decoder", &self.decoder)
+            .field("failed", &self.failed)
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "std")]
+fn invalid_data(err: Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
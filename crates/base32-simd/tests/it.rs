@@ -1,5 +1,5 @@
-use base32_simd::{AsOut, Base32};
-use base32_simd::{BASE32, BASE32HEX, BASE32HEX_NO_PAD, BASE32_NO_PAD};
+use base32_simd::{AsOut, Base32, CustomCharset};
+use base32_simd::{BASE32, BASE32HEX, BASE32HEX_NO_PAD, BASE32_NO_PAD, CROCKFORD, GEOHASH, ZBASE32};
 
 use rand::Rng;
 
@@ -38,6 +38,27 @@ fn basic() {
         (BASE32HEX, "foob", "CPNMUOG="),
         (BASE32HEX, "fooba", "CPNMUOJ1"),
         (BASE32HEX, "foobar", "CPNMUOJ1E8======"),
+        (CROCKFORD, "", ""),
+        (CROCKFORD, "f", "CR"),
+        (CROCKFORD, "fo", "CSQG"),
+        (CROCKFORD, "foo", "CSQPY"),
+        (CROCKFORD, "foob", "CSQPYRG"),
+        (CROCKFORD, "fooba", "CSQPYRK1"),
+        (CROCKFORD, "foobar", "CSQPYRK1E8"),
+        (ZBASE32, "", ""),
+        (ZBASE32, "f", "ca"),
+        (ZBASE32, "fo", "c3zo"),
+        (ZBASE32, "foo", "c3zs6"),
+        (ZBASE32, "foob", "c3zs6ao"),
+        (ZBASE32, "fooba", "c3zs6aub"),
+        (ZBASE32, "foobar", "c3zs6aubqe"),
+        (GEOHASH, "", ""),
+        (GEOHASH, "f", "ds"),
+        (GEOHASH, "fo", "dtrh"),
+        (GEOHASH, "foo", "dtrqy"),
+        (GEOHASH, "foob", "dtrqysh"),
+        (GEOHASH, "fooba", "dtrqysm1"),
+        (GEOHASH, "foobar", "dtrqysm1f8"),
     ];
 
     let mut buf: Vec<u8> = Vec::new();
@@ -118,11 +139,21 @@ fn random() {
         dbgmsg!("n = {}", n);
         let bytes = rand_bytes(n);
 
+        const LOWER: Base32 = Base32::custom(&CustomCharset::new(b"abcdefghijklmnopqrstuvwxyz234567"), true);
+        const SCALAR: Base32 = Base32::custom(&CustomCharset::new(b"ybndrfg8ejkmcpqxot1uwisza345h769"), false);
+
         let test_config = [
             BASE32,           //
             BASE32HEX,        //
             BASE32_NO_PAD,    //
             BASE32HEX_NO_PAD, //
+            CROCKFORD,        //
+            ZBASE32,          //
+            GEOHASH,          //
+            BASE32.case_insensitive(),
+            BASE32HEX_NO_PAD.case_insensitive(),
+            LOWER,
+            SCALAR,
         ];
 
         for base32 in test_config {
@@ -142,3 +173,352 @@ fn random() {
         }
     }
 }
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn crockford() {
+    use base32_simd::crockford;
+
+    let bytes = b"foobar 0123456789".repeat(10);
+    let long = CROCKFORD.encode_to_string(&bytes);
+    let lower = long.to_ascii_lowercase();
+    let confusable = long.replace('1', "l").replace('0', "O");
+    assert!(long.contains('1') && long.contains('0'));
+    assert_eq!(CROCKFORD.decode_to_vec(&long).unwrap(), bytes);
+    assert_eq!(CROCKFORD.decode_to_vec(&lower).unwrap(), bytes);
+    assert_eq!(CROCKFORD.decode_to_vec(&confusable).unwrap(), bytes);
+    assert!(CROCKFORD.check(confusable.as_bytes()).is_ok());
+    assert_eq!(CROCKFORD.decode_to_vec("cSqPyRkIe8").unwrap(), b"foobar");
+
+    for bad in ["CSQPYRKUE8", "CSQPYRK-E8", "CSQPYRK{E8", "CSQPYRK\u{fb}E8"] {
+        assert!(CROCKFORD.check(bad.as_bytes()).is_err());
+        assert!(CROCKFORD.decode_to_vec(bad).is_err());
+        let long = bad.repeat(10);
+        assert!(CROCKFORD.check(long.as_bytes()).is_err());
+        assert!(CROCKFORD.decode_to_vec(long).is_err());
+    }
+
+    assert_eq!(crockford::check_symbol(b""), b'0');
+    assert_eq!(crockford::check_symbol(&[36]), b'~');
+    assert_eq!(crockford::check_symbol(&[1, 0]), b'T');
+    assert_eq!(crockford::check_symbol(&[0, 0, 0, 0, 36]), b'U');
+    assert_eq!(crockford::encode_with_check_to_string(b"foobar"), "CSQPYRK1E8R");
+
+    // Integer implementations of the spec encode 1234 as "16J" with the check symbol "D" (1234 % 37 = 13).
+    // The same number in 5 bytes has no padding bits.
+    assert_eq!(
+        crockford::encode_with_check_to_string([0, 0, 0, 0x04, 0xd2]),
+        "0000016JD"
+    );
+    // [0x04, 0xd2] is padded to 0x4d20 = 19744 = "K90", and 19744 % 37 = 23 is "Q".
+    assert_eq!(crockford::encode_with_check_to_string([0x04, 0xd2]), "0K90Q");
+
+    assert_eq!(crockford::decode_to_vec("CSQP-YRK1-E8R", true).unwrap(), b"foobar");
+    assert_eq!(crockford::decode_to_vec("csqp-yrkl-e8r-", true).unwrap(), b"foobar");
+    assert_eq!(crockford::decode_to_vec("--CSQPYRK1E8", false).unwrap(), b"foobar");
+    assert_eq!(crockford::decode_to_vec("4g~", true).unwrap(), [36]);
+    assert_eq!(crockford::decode_to_vec("00000014u", true).unwrap(), [0, 0, 0, 0, 36]);
+    assert!(crockford::decode_to_vec("CSQPYRK1E86", true).is_err());
+    assert!(crockford::decode_to_vec("CSQPYRK1E8", true).is_err());
+    assert!(crockford::decode_to_vec("---", true).is_err());
+
+    for n in 0..600 {
+        let bytes = rand_bytes(n);
+
+        let mut buf = vec![0u8; crockford::encoded_length_with_check(n)];
+        let encoded = crockford::encode_with_check(&bytes, buf.as_out()).unwrap().to_vec();
+
+        let mut hyphenated = Vec::new();
+        for (i, &c) in encoded.iter().enumerate() {
+            if i % 5 == 4 {
+                hyphenated.push(b'-');
+            }
+            hyphenated.push(c.to_ascii_lowercase());
+        }
+
+        let mut buf = vec![0u8; crockford::decoded_length(&hyphenated, true).unwrap()];
+        let ans = crockford::decode(&hyphenated, buf.as_out(), true).unwrap();
+        assert_eq!(ans, bytes);
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn geohash() {
+    use base32_simd::geohash;
+
+    let cases: &[(&str, u64)] = &[
+        ("", 0),
+        ("ezs42", 0x00df_e082),
+        ("u4pruy", 0x344a_df5e),
+        ("u4pruydq", 0x00d1_2b7d_7996),
+        ("u4pruydqq", 0x1a25_6faf_32d6),
+        ("u4pruydqqvj", 0x0068_95be_bccb_5b71),
+        ("u4pruydqqvjg", 0x0d12_b7d7_996b_6e2f),
+        ("zzzzzzzzzzzz", 0x0fff_ffff_ffff_ffff),
+    ];
+    for &(hash, value) in cases {
+        assert_eq!(geohash::decode(hash.as_bytes()).unwrap(), value, "hash = {hash}");
+        assert_eq!(geohash::encode_to_string(value, hash.len()), hash);
+
+        let mut buf = [0u8; geohash::MAX_LEN];
+        let ans = geohash::encode(value, hash.len(), buf[..].as_out()).unwrap();
+        assert_eq!(ans, hash.as_bytes());
+    }
+
+    // whole bytes agree with the byte-oriented variant
+    assert_eq!(
+        GEOHASH.decode_to_vec("u4pruydq").unwrap(),
+        [0xd1, 0x2b, 0x7d, 0x79, 0x96]
+    );
+
+    assert!(geohash::decode(b"u4pruydqqvjgz").is_err());
+    for bad in ["u4pruydqqvja", "U4pruy", "u4pr-y", "u4pr\u{fb}"] {
+        assert!(geohash::decode(bad.as_bytes()).is_err(), "hash = {bad}");
+    }
+
+    let mut buf = [0u8; geohash::MAX_LEN];
+    assert!(geohash::encode(0, geohash::MAX_LEN + 1, buf[..].as_out()).is_err());
+    assert!(geohash::encode(1 << 30, 6, buf[..].as_out()).is_err());
+    assert!(geohash::encode(0, 6, buf[..5].as_out()).is_err());
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn lookup_alphabets() {
+    assert_eq!(GEOHASH.decode_to_vec("ezs42").unwrap(), [0x6f, 0xf0, 0x41]);
+    assert!(GEOHASH.decode_to_vec("ezs43").is_err());
+
+    let cases: &[(Base32, &[u8])] = &[(ZBASE32, b"02lvYB \x7f\x80\xff"), (GEOHASH, b"ailoAZ \x7f\x80\xff")];
+    for &(ref base32, invalid) in cases {
+        let bytes = rand_bytes(100);
+        let encoded = base32.encode_to_string(&bytes);
+        assert!(base32.check(encoded.as_bytes()).is_ok());
+
+        for &c in invalid {
+            for i in [0, 17, 31, 63, encoded.len() - 1] {
+                let mut buf = encoded.clone().into_bytes();
+                buf[i] = c;
+                assert!(base32.check(&buf).is_err());
+                assert!(base32.decode_to_vec(&buf).is_err());
+            }
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn case_insensitive() {
+    let cases: &[(Base32, &str, &str)] = &[
+        (BASE32.case_insensitive(), "foobar", "mzxw6ytboi======"),
+        (BASE32.case_insensitive(), "foobar", "MzXw6YtBoI======"),
+        (BASE32_NO_PAD.case_insensitive(), "fooba", "mzxw6ytb"),
+        (BASE32HEX.case_insensitive(), "foobar", "cpnmuoj1e8======"),
+        (BASE32HEX_NO_PAD.case_insensitive(), "fooba", "CpNmUoJ1"),
+    ];
+    for &(ref base32, input, output) in cases {
+        assert_eq!(base32.decode_to_vec(output).unwrap(), input.as_bytes());
+        assert_eq!(base32.encode_to_string(input), output.to_ascii_uppercase());
+    }
+
+    assert!(BASE32.decode_to_vec("mzxw6ytboi======").is_err());
+    assert!(BASE32HEX.decode_to_vec("cpnmuoj1e8======").is_err());
+
+    let configs = [BASE32_NO_PAD.case_insensitive(), BASE32HEX_NO_PAD.case_insensitive()];
+    for base32 in &configs {
+        let bytes = rand_bytes(100);
+        let lower = base32.encode_to_string(&bytes).to_ascii_lowercase();
+        assert!(base32.check(lower.as_bytes()).is_ok());
+        assert_eq!(base32.decode_to_vec(&lower).unwrap(), bytes);
+
+        for c in [b'=', b'@', b'[', b'`', b'{', b'w', b'z', b'1', b'8', 0x80] {
+            if base32.charset().contains(&c.to_ascii_uppercase()) {
+                continue;
+            }
+            for i in [0, 31, 63, lower.len() - 1] {
+                let mut buf = lower.clone().into_bytes();
+                buf[i] = c;
+                assert!(base32.check(&buf).is_err());
+                assert!(base32.decode_to_vec(&buf).is_err());
+            }
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn custom() {
+    const LOWER: Base32 = Base32::custom(&CustomCharset::new(b"abcdefghijklmnopqrstuvwxyz234567"), true);
+    const SYMBOLS: Base32 = Base32::custom(&CustomCharset::new(b"!#$%&()*+,-./:;<>?@[]^_`{|}~0123"), false);
+    const SCALAR: Base32 = Base32::custom(&CustomCharset::new(b"ybndrfg8ejkmcpqxot1uwisza345h769"), false);
+
+    assert_eq!(LOWER.encode_to_string("foobar"), "mzxw6ytboi======");
+    assert_eq!(LOWER.decode_to_vec("mzxw6ytboi======").unwrap(), b"foobar");
+    assert!(LOWER.decode_to_vec("MZXW6YTBOI======").is_err());
+
+    assert_eq!(LOWER.charset(), b"abcdefghijklmnopqrstuvwxyz234567");
+    assert_eq!(LOWER.custom_charset().unwrap().charset(), LOWER.charset());
+    assert!(BASE32.custom_charset().is_none());
+
+    for base32 in [&LOWER, &SYMBOLS, &SCALAR] {
+        let bytes = rand_bytes(200);
+        let encoded = base32.encode_to_string(&bytes);
+        assert!(base32.check(encoded.as_bytes()).is_ok());
+        assert_eq!(base32.decode_to_vec(&encoded).unwrap(), bytes);
+
+        let expected = BASE32_NO_PAD.encode_to_string(&bytes);
+        let mapped: Vec<u8> = expected
+            .bytes()
+            .map(|c| base32.charset()[BASE32.charset().iter().position(|&x| x == c).unwrap()])
+            .collect();
+        assert_eq!(&encoded.as_bytes()[..mapped.len()], mapped);
+
+        for i in [0, 31, 100, encoded.len() - 8] {
+            for c in [b'"', b'\\', b'A', 0x80, 0xfe] {
+                if base32.charset().contains(&c) {
+                    continue;
+                }
+                let mut buf = encoded.clone().into_bytes();
+                buf[i] = c;
+                assert!(base32.check(&buf).is_err());
+                assert!(base32.decode_to_vec(&buf).is_err());
+            }
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn stream() {
+    use base32_simd::stream::{Decoder, Encoder};
+    use rand::RngExt;
+
+    fn chunks(data: &[u8]) -> Vec<&[u8]> {
+        let mut ans = Vec::new();
+        let mut rest = data;
+        while !rest.is_empty() {
+            let k = rand::rng().random_range(0..=rest.len().min(20));
+            ans.push(&rest[..k]);
+            rest = &rest[k..];
+        }
+        ans
+    }
+
+    for n in 0..128 {
+        let bytes = rand_bytes(n);
+
+        for base32 in [BASE32, BASE32_NO_PAD, CROCKFORD, ZBASE32.case_insensitive()] {
+            let expected = base32.encode_to_string(&bytes);
+
+            let mut encoded = Vec::new();
+            let mut encoder = Encoder::new(&base32);
+            for chunk in chunks(&bytes) {
+                let mut buf = vec![0u8; encoder.output_length(chunk.len())];
+                encoded.extend_from_slice(encoder.encode(chunk, buf.as_out()).unwrap());
+            }
+            encoded.extend_from_slice(encoder.finish([0u8; 8][..].as_out()).unwrap());
+            assert_eq!(encoded, expected.as_bytes());
+
+            let mut decoded = Vec::new();
+            let mut decoder = Decoder::new(&base32);
+            for chunk in chunks(&encoded) {
+                let mut buf = vec![0u8; decoder.output_length(chunk.len())];
+                decoded.extend_from_slice(decoder.decode(chunk, buf.as_out()).unwrap());
+            }
+            decoded.extend_from_slice(decoder.finish([0u8; 5][..].as_out()).unwrap());
+            assert_eq!(decoded, bytes);
+        }
+    }
+
+    let decode = |src: &[u8]| {
+        let mut decoder = Decoder::new(&BASE32);
+        let mut buf = [0u8; 16];
+        decoder.decode(src, buf[..].as_out())?;
+        decoder.finish(buf[..].as_out()).map(|_| ())
+    };
+    assert!(decode(b"MZXW6YTBOI======").is_ok());
+    assert!(decode(b"MY======MZXW6===").is_err());
+    assert!(decode(b"MZXW6YTBOI").is_err());
+    assert!(decode(b"MZXW6YT1").is_err());
+
+    let mut encoder = Encoder::new(&BASE32);
+    assert!(encoder.encode(b"foobar", [0u8; 7][..].as_out()).is_err());
+    assert!(encoder.encode(b"foobar", [0u8; 8][..].as_out()).is_ok());
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn stream_io() {
+    use base32_simd::stream::{DecoderReader, Encoder, EncoderWriter};
+    use std::io::{Read, Write};
+
+    for n in [0, 1, 5, 7, 1019, 1020, 1021, 4096, 10007] {
+        let bytes = rand_bytes(n);
+
+        for base32 in [BASE32, BASE32HEX_NO_PAD, GEOHASH] {
+            let mut writer = EncoderWriter::new(&base32, Vec::new());
+            for chunk in bytes.chunks(333) {
+                writer.write_all(chunk).unwrap();
+            }
+            let encoded = writer.finish().unwrap();
+            assert_eq!(encoded, base32.encode_to_string(&bytes).as_bytes());
+
+            let mut reader = DecoderReader::new(&base32, encoded.as_slice());
+            let mut decoded = Vec::new();
+            reader.read_to_end(&mut decoded).unwrap();
+            assert_eq!(decoded, bytes);
+        }
+    }
+
+    let mut reader = DecoderReader::new(&BASE32, b"MZXW6YTBOI=====".as_slice());
+    let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let input = [b"MZXW6YT!".as_slice(), &[b'A'; 2048]].concat();
+    let mut reader = DecoderReader::new(&BASE32, input.as_slice());
+    let mut buf = [0u8; 16];
+    for _ in 0..3 {
+        let err = reader.read(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    let writer = EncoderWriter::new(&BASE32, Vec::<u8>::new());
+    assert_eq!(
+        format!("{writer:?}"),
+        format!(
+            "EncoderWriter {{ encoder: {:?}, inner: [], .. }}",
+            Encoder::new(&BASE32)
+        )
+    );
+    let reader = DecoderReader::new(&BASE32, b"".as_slice());
+    assert!(format!("{reader:?}").starts_with("DecoderReader { decoder: Some(Decoder {"));
+}
+
+// RUSTFLAGS=-Zsanitizer=address cargo test -p base32-simd --features=parallel -- --include-ignored parallel
+#[cfg(all(not(miri), feature = "parallel"))]
+#[test]
+#[ignore = "slow"]
+fn parallel() {
+    let mut buf1 = vec![0; 200_000];
+    let mut buf2 = vec![0; 200_000];
+    let mut buf3 = vec![0; 100_000];
+    for n in (0..100_000).step_by(997) {
+        let src = rand_bytes(n);
+        for base32 in [BASE32, BASE32HEX_NO_PAD, ZBASE32, BASE32.case_insensitive()] {
+            let ans1 = base32.par_encode(&src, buf1.as_out()).unwrap();
+            let ans2 = base32.encode(&src, buf2.as_out()).unwrap();
+            assert!(ans1 == ans2, "n = {n}");
+
+            assert!(base32.par_check(ans1).is_ok());
+            let ans3 = base32.par_decode(ans1, buf3.as_out()).unwrap();
+            assert!(ans3 == src, "n = {n}");
+
+            if n > 0 {
+                ans1[n / 2] = b'!';
+                assert!(base32.par_check(ans1).is_err());
+                assert!(base32.par_decode(ans1, buf3.as_out()).is_err());
+            }
+        }
+    }
+}
@@ -24,25 +24,7 @@ fn lookup_ascii_whitespace(c: u8) -> u8 {
 
 #[inline(always)]
 fn has_ascii_whitespace<S: Scalable<V>, V: POD>(s: S, x: V) -> bool {
-    // ASCII whitespaces
-    // TAB      0x09    00001001
-    // LF       0x0a    00001010
-    // FF       0x0c    00001100
-    // CR       0x0d    00001101
-    // SPACE    0x20    00010000
-    //
-
-    // m1 = {{byte in 0x09..=0x0d}}x32
-    let m1 = s.i8xn_lt(s.u8xn_sub(x, s.u8xn_splat(0x89)), s.i8xn_splat(-128 + 5));
-
-    // m2 = {{byte == 0x0b}}
-    let m2 = s.u8xn_eq(x, s.u8xn_splat(0x0b));
-
-    // m3 = {{byte is SPACE}}
-    let m3 = s.u8xn_eq(x, s.u8xn_splat(0x20));
-
-    // any((m1 & !m2) | m3)
-    s.mask8xn_any(s.or(s.andnot(m1, m2), m3))
+    s.mask8xn_any(vsimd::ascii::ascii_whitespace_mask(s, x))
 }
 
 #[inline(always)]
@@ -1,9 +1,9 @@
-use crate::alsw::{STANDARD_ALSW_CHECK_X2, URL_SAFE_ALSW_CHECK_X2};
 use crate::decode::{decode_ascii4, decode_ascii8, decode_extra};
 use crate::decode::{STANDARD_DECODE_TABLE, URL_SAFE_DECODE_TABLE};
 use crate::{Config, Error, Kind};
 
 use vsimd::alsw::AlswLut;
+use vsimd::base64::{STANDARD_ALSW_CHECK_X2, URL_SAFE_ALSW_CHECK_X2};
 use vsimd::vector::V256;
 use vsimd::SIMD256;
 
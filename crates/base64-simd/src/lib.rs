@@ -52,7 +52,6 @@ extern crate alloc;
 mod error;
 pub use self::error::Error;
 
-mod alsw;
 mod ascii;
 mod check;
 mod decode;
@@ -76,14 +75,12 @@ pub use outref::{AsOut, Out};
 use crate::decode::decoded_length;
 use crate::encode::encoded_length_unchecked;
 
+use vsimd::base64::{STANDARD_CHARSET, URL_SAFE_CHARSET};
 use vsimd::tools::{slice_mut, slice_parts};
 
 #[cfg(all(feature = "alloc", not(any(test, feature = "std"))))]
 use alloc::{string::String, vec::Vec};
 
-const STANDARD_CHARSET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
-const URL_SAFE_CHARSET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
-
 /// Base64 variant
 #[derive(Debug)]
 pub struct Base64 {
@@ -96,7 +96,7 @@ fn random() {
             ]
         };
 
-        for (base64, config) in test_config.into_iter().zip(base_config.into_iter()) {
+        for (base64, config) in test_config.into_iter().zip(base_config) {
             dbgmsg!("base64 = {:?}", base64);
 
             let encoded = config.encode(&bytes);
@@ -0,0 +1,280 @@
+//! Array literal formatting and parsing.
+//!
+//! The output is the body of a C or Rust byte array, the same as `xxd -i`.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use hex_simd::array::Array;
+//!
+//! let text = Array::new().columns(8).emit_to_string(b"Hello world!");
+//! assert_eq!(text, "  0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x77, 0x6f,\n  0x72, 0x6c, 0x64, 0x21\n");
+//!
+//! let bytes = Array::new().parse_to_vec(text).unwrap();
+//! assert_eq!(bytes, b"Hello world!");
+//! # }
+//! ```
+
+use crate::{AsciiCase, Error, Out};
+
+use vsimd::tools::slice_mut;
+
+use core::ptr::write_bytes;
+
+#[cfg(all(feature = "alloc", not(any(test, feature = "std"))))]
+use alloc::{string::String, vec::Vec};
+
+const BLOCK_SIZE: usize = 256;
+
+/// Array literal formatter and parser
+#[derive(Debug, Clone, Copy)]
+pub struct Array {
+    columns: usize,
+    indent: usize,
+    case: AsciiCase,
+}
+
+impl Array {
+    /// Creates a formatter with the default settings of `xxd -i`.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            columns: 12,
+            indent: 2,
+            case: AsciiCase::Lower,
+        }
+    }
+
+    /// Sets the number of bytes per line (`xxd -c`).
+    ///
+    /// # Panics
+    /// This function asserts that `n >= 1`.
+    #[inline]
+    #[must_use]
+    pub const fn columns(mut self, n: usize) -> Self {
+        assert!(n >= 1);
+        self.columns = n;
+        self
+    }
+
+    /// Sets the number of spaces at the start of each line.
+    #[inline]
+    #[must_use]
+    pub const fn indent(mut self, n: usize) -> Self {
+        self.indent = n;
+        self
+    }
+
+    /// Sets the ascii case of hex digits.
+    ///
+    /// The prefix is always `0x` so that the output is also a valid Rust literal.
+    #[inline]
+    #[must_use]
+    pub const fn case(mut self, case: AsciiCase) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Calculates the emitted length.
+    ///
+    /// # Panics
+    /// This function panics if the result overflows.
+    #[inline]
+    #[must_use]
+    pub fn emitted_length(&self, n: usize) -> usize {
+        if n == 0 {
+            return 0;
+        }
+        let lines = n.div_ceil(self.columns);
+        // "0xHH" and ", " or ",\n" per byte, except a single "\n" after the last one
+        let total = n
+            .checked_mul(6)
+            .and_then(|m| m.checked_add(lines.checked_mul(self.indent)?));
+        total.expect("length overflow") - 1
+    }
+
+    /// Formats bytes to an array literal body.
+    ///
+    /// # Errors
+    /// This function returns `Err` if the length of `dst` is not enough.
+    #[inline]
+    pub fn emit<'d>(&self, src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+        let m = self.emitted_length(src.len());
+        ensure!(dst.len() >= m);
+        unsafe {
+            let dst = dst.as_mut_ptr();
+            self.emit_raw(src, dst);
+            Ok(slice_mut(dst, m))
+        }
+    }
+
+    /// Formats bytes to an array literal body and returns [`&mut str`](str).
+    ///
+    /// # Errors
+    /// This function returns `Err` if the length of `dst` is not enough.
+    #[inline]
+    pub fn emit_as_str<'d>(&self, src: &[u8], dst: Out<'d, [u8]>) -> Result<&'d mut str, Error> {
+        let ans = self.emit(src, dst)?;
+        Ok(unsafe { core::str::from_utf8_unchecked_mut(ans) })
+    }
+
+    /// Formats bytes to an array literal body.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    pub fn emit_to_string(&self, data: impl AsRef<[u8]>) -> String {
+        let src = data.as_ref();
+        let m = self.emitted_length(src.len());
+        let mut buf: Vec<u8> = Vec::with_capacity(m);
+        unsafe {
+            self.emit_raw(src, buf.as_mut_ptr());
+            buf.set_len(m);
+            String::from_utf8_unchecked(buf)
+        }
+    }
+
+    /// Parses an array literal body to bytes.
+    ///
+    /// The elements are hex literals of one or two digits, with a `0x` or `0X` prefix,
+    /// separated by commas and ascii whitespace. A trailing comma is allowed.
+    /// Layout settings are ignored.
+    ///
+    /// # Errors
+    /// This function returns `Err` if
+    /// + the length of `dst` is not enough.
+    /// + the content of `src` is invalid.
+    #[inline]
+    pub fn parse<'d>(&self, src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+        let cap = dst.len();
+        let dst = dst.as_mut_ptr();
+        let mut len = 0;
+        parse_elements(src, |hex| {
+            let m = hex.len() / 2;
+            ensure!(cap - len >= m);
+            unsafe {
+                crate::decode_raw(hex.as_ptr(), hex.len(), dst.add(len))?;
+            }
+            len += m;
+            Ok(())
+        })?;
+        Ok(unsafe { slice_mut(dst, len) })
+    }
+
+    /// Parses an array literal body to bytes.
+    ///
+    /// # Errors
+    /// This function returns `Err` if the content of `data` is invalid.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn parse_to_vec(&self, data: impl AsRef<[u8]>) -> Result<Vec<u8>, Error> {
+        let mut buf = Vec::new();
+        parse_elements(data.as_ref(), |hex| crate::decode_append(hex, &mut buf))?;
+        Ok(buf)
+    }
+
+    unsafe fn emit_raw(&self, src: &[u8], mut dst: *mut u8) {
+        let mut hex = [0u8; BLOCK_SIZE * 2];
+        let mut remaining = src.len();
+        let mut column = 0;
+
+        for block in src.chunks(BLOCK_SIZE) {
+            crate::multiversion::encode::auto(block.as_ptr(), block.len(), hex.as_mut_ptr(), self.case);
+
+            for pair in hex[..block.len() * 2].chunks_exact(2) {
+                if column == 0 {
+                    write_bytes(dst, b' ', self.indent);
+                    dst = dst.add(self.indent);
+                }
+
+                dst.write(b'0');
+                dst.add(1).write(b'x');
+                dst.add(2).write(pair[0]);
+                dst.add(3).write(pair[1]);
+                dst = dst.add(4);
+
+                remaining -= 1;
+                column += 1;
+
+                if remaining == 0 {
+                    dst.write(b'\n');
+                } else if column == self.columns {
+                    dst.write(b',');
+                    dst.add(1).write(b'\n');
+                    dst = dst.add(2);
+                    column = 0;
+                } else {
+                    dst.write(b',');
+                    dst.add(1).write(b' ');
+                    dst = dst.add(2);
+                }
+            }
+        }
+    }
+}
+
+impl Default for Array {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collects the hex digits of elements into blocks and passes each block to `f`.
+fn parse_elements(src: &[u8], mut f: impl FnMut(&[u8]) -> Result<(), Error>) -> Result<(), Error> {
+    let mut hex = [0u8; BLOCK_SIZE * 2];
+    let mut n = 0;
+
+    let mut i = skip_whitespace(src, 0);
+    while i < src.len() {
+        ensure!(matches!(src.get(i..i + 2), Some([b'0', b'x' | b'X'])));
+        i += 2;
+
+        let digits = src[i..]
+            .iter()
+            .take(3)
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .count();
+        match digits {
+            1 => {
+                hex[n] = b'0';
+                hex[n + 1] = src[i];
+            }
+            2 => {
+                hex[n] = src[i];
+                hex[n + 1] = src[i + 1];
+            }
+            _ => return Err(Error::new()),
+        }
+        i += digits;
+        n += 2;
+
+        if n == hex.len() {
+            f(&hex)?;
+            n = 0;
+        }
+
+        i = skip_whitespace(src, i);
+        if i < src.len() {
+            ensure!(src[i] == b',');
+            i = skip_whitespace(src, i + 1);
+        }
+    }
+
+    if n > 0 {
+        f(&hex[..n])?;
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn skip_whitespace(src: &[u8], mut i: usize) -> usize {
+    while i < src.len() && src[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    i
+}
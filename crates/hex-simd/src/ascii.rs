@@ -0,0 +1,57 @@
+use vsimd::isa::{SSSE3, WASM128};
+use vsimd::vector::V64;
+use vsimd::{matches_isa, Scalable, SIMD256};
+
+#[inline(always)]
+unsafe fn remove_ascii_whitespace_short(mut src: *const u8, len: usize, mut dst: *mut u8) -> *mut u8 {
+    let end = src.add(len);
+    while src < end {
+        let x = src.read();
+        dst.write(x);
+        dst = dst.add(usize::from(!x.is_ascii_whitespace()));
+        src = src.add(1);
+    }
+    dst
+}
+
+#[inline(always)]
+pub unsafe fn remove_ascii_whitespace_fallback(src: *const u8, len: usize, dst: *mut u8) -> usize {
+    let end = remove_ascii_whitespace_short(src, len, dst);
+    end.offset_from(dst) as usize
+}
+
+#[inline(always)]
+pub unsafe fn remove_ascii_whitespace_simd<S: SIMD256>(s: S, mut src: *const u8, len: usize, dst: *mut u8) -> usize {
+    let base = dst;
+    let mut dst = dst;
+
+    let end = src.add(len / 16 * 16);
+    while src < end {
+        let x = s.v128_load_unaligned(src);
+        let is_whitespace = vsimd::ascii::ascii_whitespace_mask(s, x);
+
+        if matches_isa!(S, SSSE3 | WASM128) {
+            let mask = s.u8x16_bitmask(is_whitespace);
+            if mask == 0 {
+                s.v128_store_unaligned(dst, x);
+                dst = dst.add(16);
+            } else {
+                let (y, count) = vsimd::table::u8x16_compress(s, x, mask);
+                let (y1, y2) = y.to_v64x2();
+                dst.cast::<V64>().write_unaligned(y1);
+                dst.add(count).cast::<V64>().write_unaligned(y2);
+                dst = dst.add(16 - mask.count_ones() as usize);
+            }
+        } else if s.mask8xn_any(is_whitespace) {
+            dst = remove_ascii_whitespace_short(src, 16, dst);
+        } else {
+            s.v128_store_unaligned(dst, x);
+            dst = dst.add(16);
+        }
+
+        src = src.add(16);
+    }
+
+    let dst = remove_ascii_whitespace_short(src, len % 16, dst);
+    dst.offset_from(base) as usize
+}
@@ -1,6 +1,6 @@
-use crate::Error;
+use crate::{AsciiCase, Error};
 
-use vsimd::hex::unhex;
+use vsimd::hex::{unhex, unhex_case};
 use vsimd::isa::{AVX2, WASM128};
 use vsimd::{matches_isa, SIMD256};
 
@@ -81,3 +81,44 @@ pub unsafe fn check_simd<S: SIMD256>(s: S, mut src: *const u8, mut len: usize) -
         check_short(src, len)
     }
 }
+
+#[inline(always)]
+pub unsafe fn check_case_fallback(mut src: *const u8, len: usize, case: AsciiCase) -> Result<(), Error> {
+    let mut flag = 0;
+    let end = src.add(len);
+    while src < end {
+        let x = src.read();
+        flag |= unhex_case(x, case);
+        src = src.add(1);
+    }
+    ensure!(flag != 0xff);
+    Ok(())
+}
+
+#[inline(always)]
+pub unsafe fn check_case_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    mut len: usize,
+    case: AsciiCase,
+) -> Result<(), Error> {
+    if matches_isa!(S, AVX2) {
+        let end = src.add(len / 32 * 32);
+        while src < end {
+            let x = s.v256_load_unaligned(src);
+            ensure!(vsimd::hex::check_case32(s, x, case));
+            src = src.add(32);
+        }
+        len %= 32;
+    }
+
+    let end = src.add(len / 16 * 16);
+    while src < end {
+        let x = s.v128_load_unaligned(src);
+        ensure!(vsimd::hex::check_case16(s, x, case));
+        src = src.add(16);
+    }
+    len %= 16;
+
+    check_case_fallback(src, len, case)
+}
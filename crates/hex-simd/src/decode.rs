@@ -1,6 +1,6 @@
-use crate::Error;
+use crate::{AsciiCase, Error};
 
-use vsimd::hex::unhex;
+use vsimd::hex::{unhex, unhex_case};
 use vsimd::is_isa_type;
 use vsimd::isa::{Fallback, InstructionSet, AVX2, SSE2, WASM128};
 use vsimd::matches_isa;
@@ -14,16 +14,29 @@ fn shl4(x: u8) -> u8 {
 }
 
 #[inline(always)]
-unsafe fn decode_bits(src: *const u8, dst: *mut u8) -> u8 {
-    let y1 = unhex(read(src, 0));
-    let y2 = unhex(read(src, 1));
+fn unhex_opt(x: u8, case: Option<AsciiCase>) -> u8 {
+    match case {
+        None => unhex(x),
+        Some(case) => unhex_case(x, case),
+    }
+}
+
+#[inline(always)]
+unsafe fn decode_bits(src: *const u8, dst: *mut u8, case: Option<AsciiCase>) -> u8 {
+    let y1 = unhex_opt(read(src, 0), case);
+    let y2 = unhex_opt(read(src, 1), case);
     let z = shl4(y1) | y2;
     dst.write(z);
     y1 | y2
 }
 
 #[inline(always)]
-unsafe fn decode_short<S>(mut src: *const u8, len: usize, mut dst: *mut u8) -> Result<(), Error>
+unsafe fn decode_short<S>(
+    mut src: *const u8,
+    len: usize,
+    mut dst: *mut u8,
+    case: Option<AsciiCase>,
+) -> Result<(), Error>
 where
     S: InstructionSet,
 {
@@ -31,7 +44,7 @@ where
     if matches_isa!(S, AVX2 | WASM128) {
         let end = src.add(len);
         while src < end {
-            let flag = decode_bits(src, dst);
+            let flag = decode_bits(src, dst, case);
             ensure!(flag != 0xff);
             src = src.add(2);
             dst = dst.add(1);
@@ -41,7 +54,7 @@ where
         let end = src.add(len);
         let mut flag = 0;
         while src < end {
-            flag |= decode_bits(src, dst);
+            flag |= decode_bits(src, dst, case);
             src = src.add(2);
             dst = dst.add(1);
         }
@@ -51,39 +64,53 @@ where
 }
 
 #[inline(always)]
-unsafe fn decode_long(mut src: *const u8, len: usize, mut dst: *mut u8) -> Result<(), Error> {
+unsafe fn decode_long(mut src: *const u8, len: usize, mut dst: *mut u8, case: Option<AsciiCase>) -> Result<(), Error> {
     let end = src.add(len / 16 * 16);
     while src < end {
         let mut flag = 0;
         let mut i = 0;
         while i < 8 {
-            flag |= decode_bits(src, dst);
+            flag |= decode_bits(src, dst, case);
             src = src.add(2);
             dst = dst.add(1);
             i += 1;
         }
         ensure!(flag != 0xff);
     }
-    decode_short::<Fallback>(src, len % 16, dst)
+    decode_short::<Fallback>(src, len % 16, dst, case)
 }
 
 #[inline(always)]
 pub unsafe fn decode_fallback(src: *const u8, len: usize, dst: *mut u8) -> Result<(), Error> {
-    decode_long(src, len, dst)
+    decode_long(src, len, dst, None)
+}
+
+#[inline(always)]
+pub unsafe fn decode_case_fallback(src: *const u8, len: usize, dst: *mut u8, case: AsciiCase) -> Result<(), Error> {
+    match case {
+        AsciiCase::Lower => decode_long(src, len, dst, Some(AsciiCase::Lower)),
+        AsciiCase::Upper => decode_long(src, len, dst, Some(AsciiCase::Upper)),
+    }
 }
 
 #[inline(always)]
-unsafe fn decode16<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
+unsafe fn decode16<S: SIMD256>(s: S, src: *const u8, dst: *mut u8, case: Option<AsciiCase>) -> Result<(), Error> {
     let x = s.v128_load_unaligned(src);
-    let y = try_!(vsimd::hex::decode_ascii16(s, x));
+    let y = match case {
+        None => try_!(vsimd::hex::decode_ascii16(s, x)),
+        Some(case) => try_!(vsimd::hex::decode_case_ascii16(s, x, case)),
+    };
     dst.cast::<V64>().write_unaligned(y);
     Ok(())
 }
 
 #[inline(always)]
-unsafe fn decode32<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
+unsafe fn decode32<S: SIMD256>(s: S, src: *const u8, dst: *mut u8, case: Option<AsciiCase>) -> Result<(), Error> {
     let x = s.v256_load_unaligned(src);
-    let y = try_!(vsimd::hex::decode_ascii32(s, x));
+    let y = match case {
+        None => try_!(vsimd::hex::decode_ascii32(s, x)),
+        Some(case) => try_!(vsimd::hex::decode_case_ascii32(s, x, case)),
+    };
     s.v128_store_unaligned(dst, y);
     Ok(())
 }
@@ -95,10 +122,25 @@ pub unsafe fn decode_simd<S: SIMD256>(s: S, src: *const u8, len: usize, dst: *mu
             return decode_simd_sse2(SSE2::new(), src, len, dst);
         }
         if matches_isa!(S, AVX2) {
-            return decode_simd_v256(s, src, len, dst);
+            return decode_simd_v256(s, src, len, dst, None);
         }
     }
-    decode_simd_v128(s, src, len, dst)
+    decode_simd_v128(s, src, len, dst, None)
+}
+
+#[inline(always)]
+pub unsafe fn decode_case_simd<S: SIMD256>(
+    s: S,
+    src: *const u8,
+    len: usize,
+    dst: *mut u8,
+    case: AsciiCase,
+) -> Result<(), Error> {
+    let case = Some(case);
+    if matches_isa!(S, AVX2) {
+        return decode_simd_v256(s, src, len, dst, case);
+    }
+    decode_simd_v128(s, src, len, dst, case)
 }
 
 #[inline(always)]
@@ -107,13 +149,14 @@ pub unsafe fn decode_simd_v256<S: SIMD256>(
     mut src: *const u8,
     mut len: usize,
     mut dst: *mut u8,
+    case: Option<AsciiCase>,
 ) -> Result<(), Error> {
     if len == 16 {
-        return decode16(s, src, dst);
+        return decode16(s, src, dst, case);
     }
 
     if len == 32 {
-        return decode32(s, src, dst);
+        return decode32(s, src, dst, case);
     }
 
     let end = src.add(len / 64 * 64);
@@ -125,7 +168,10 @@ pub unsafe fn decode_simd_v256<S: SIMD256>(
         src = src.add(32);
 
         let x = (x0, x1);
-        let y = try_!(vsimd::hex::decode_ascii32x2(s, x));
+        let y = match case {
+            None => try_!(vsimd::hex::decode_ascii32x2(s, x)),
+            Some(case) => try_!(vsimd::hex::decode_case_ascii32x2(s, x, case)),
+        };
         s.v256_store_unaligned(dst, y);
         dst = dst.add(32);
     }
@@ -136,20 +182,20 @@ pub unsafe fn decode_simd_v256<S: SIMD256>(
     }
 
     if len >= 32 {
-        decode32(s, src, dst)?;
+        decode32(s, src, dst, case)?;
         src = src.add(32);
         dst = dst.add(16);
         len -= 32;
     }
 
     if len >= 16 {
-        decode16(s, src, dst)?;
+        decode16(s, src, dst, case)?;
         src = src.add(16);
         dst = dst.add(8);
         len -= 16;
     }
 
-    decode_short::<S>(src, len, dst)
+    decode_short::<S>(src, len, dst, case)
 }
 
 #[inline(always)]
@@ -158,10 +204,11 @@ pub unsafe fn decode_simd_v128<S: SIMD256>(
     mut src: *const u8,
     mut len: usize,
     mut dst: *mut u8,
+    case: Option<AsciiCase>,
 ) -> Result<(), Error> {
     let end = src.add(len / 32 * 32);
     while src < end {
-        decode32(s, src, dst)?;
+        decode32(s, src, dst, case)?;
         src = src.add(32);
         dst = dst.add(16);
     }
@@ -171,12 +218,12 @@ pub unsafe fn decode_simd_v128<S: SIMD256>(
         return Ok(());
     }
     if len >= 16 {
-        decode16(s, src, dst)?;
+        decode16(s, src, dst, case)?;
         src = src.add(16);
         dst = dst.add(8);
         len -= 16;
     }
-    decode_short::<S>(src, len, dst)
+    decode_short::<S>(src, len, dst, case)
 }
 
 #[inline(always)]
@@ -200,5 +247,5 @@ pub unsafe fn decode_simd_sse2(s: SSE2, mut src: *const u8, mut len: usize, mut
         return Ok(());
     }
 
-    decode_short::<SSE2>(src, len, dst)
+    decode_short::<SSE2>(src, len, dst, None)
 }
@@ -0,0 +1,473 @@
+//! Hexdump formatting and parsing.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use hex_simd::dump::XXD;
+//!
+//! let text = XXD.dump_to_string(b"Hello world!\n");
+//! assert_eq!(text, "00000000: 4865 6c6c 6f20 776f 726c 6421 0a         Hello world!.\n");
+//!
+//! let bytes = XXD.parse_to_vec(text).unwrap();
+//! assert_eq!(bytes, b"Hello world!\n");
+//! # }
+//! ```
+
+use crate::{AsciiCase, Error, Out};
+
+use vsimd::tools::{slice_mut, write};
+
+use core::ptr::{copy_nonoverlapping, write_bytes};
+
+#[cfg(all(feature = "alloc", not(any(test, feature = "std"))))]
+use alloc::{string::String, vec::Vec};
+
+const MAX_COLUMNS: usize = 256;
+
+/// The number of bytes encoded at once when dumping.
+const BLOCK_SIZE: usize = 2048;
+
+/// Hexdump layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// The layout of `xxd`.
+    ///
+    /// ```text
+    /// 00000000: 4865 6c6c 6f20 776f 726c 6421 0a         Hello world!.
+    /// ```
+    Xxd,
+
+    /// The layout of `hexdump -C`.
+    ///
+    /// Repeated lines are not collapsed, which is the same as `hexdump -C -v`.
+    ///
+    /// ```text
+    /// 00000000  48 65 6c 6c 6f 20 77 6f  72 6c 64 21 0a           |Hello world!.|
+    /// 0000000d
+    /// ```
+    Canonical,
+}
+
+/// Hexdump formatter and parser
+#[derive(Debug, Clone, Copy)]
+pub struct Dump {
+    style: Style,
+    columns: usize,
+    group: usize,
+    case: AsciiCase,
+    offset: u64,
+}
+
+/// The default layout of `xxd`.
+pub const XXD: Dump = Dump::new(Style::Xxd);
+
+/// The default layout of `hexdump -C`.
+pub const CANONICAL: Dump = Dump::new(Style::Canonical);
+
+impl Dump {
+    /// Creates a formatter with the default settings of `style`.
+    #[inline]
+    #[must_use]
+    pub const fn new(style: Style) -> Self {
+        let group = match style {
+            Style::Xxd => 2,
+            Style::Canonical => 8,
+        };
+        Self {
+            style,
+            columns: 16,
+            group,
+            case: AsciiCase::Lower,
+            offset: 0,
+        }
+    }
+
+    /// Sets the number of bytes per line (`xxd -c`).
+    ///
+    /// # Panics
+    /// This function asserts that `1 <= n <= 256`.
+    #[inline]
+    #[must_use]
+    pub const fn columns(mut self, n: usize) -> Self {
+        assert!(n >= 1 && n <= MAX_COLUMNS);
+        self.columns = n;
+        self
+    }
+
+    /// Sets the number of bytes per group (`xxd -g`).
+    ///
+    /// # Panics
+    /// This function asserts that `n >= 1`.
+    #[inline]
+    #[must_use]
+    pub const fn group(mut self, n: usize) -> Self {
+        assert!(n >= 1);
+        self.group = n;
+        self
+    }
+
+    /// Sets the ascii case of hex bytes (`xxd -u`).
+    ///
+    /// The offset column is always lowercase, which matches `xxd -u` of xxd 2022-01-14.
+    #[inline]
+    #[must_use]
+    pub const fn case(mut self, case: AsciiCase) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Sets the offset of the first byte (`xxd -o`).
+    #[inline]
+    #[must_use]
+    pub const fn offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    #[inline(always)]
+    const fn group_size(&self) -> usize {
+        if self.group < self.columns {
+            self.group
+        } else {
+            self.columns
+        }
+    }
+
+    /// Width of the hex area, including trailing separators.
+    #[inline(always)]
+    const fn hex_width(&self) -> usize {
+        let c = self.columns;
+        let groups = c.div_ceil(self.group_size());
+        match self.style {
+            Style::Xxd => c * 2 + groups,
+            Style::Canonical => c * 3 + (groups - 1),
+        }
+    }
+
+    /// Width of a line without the offset column and the ascii column.
+    #[inline(always)]
+    const fn line_overhead(&self) -> usize {
+        match self.style {
+            // ": " + hex + " " + "\n"
+            Style::Xxd => 2 + self.hex_width() + 1 + 1,
+            // "  " + hex + " |" + "|\n"
+            Style::Canonical => 2 + self.hex_width() + 2 + 2,
+        }
+    }
+
+    /// Calculates the dumped length.
+    ///
+    /// # Panics
+    /// This function panics if the offsets or the result overflow.
+    #[inline]
+    #[must_use]
+    pub fn dumped_length(&self, n: usize) -> usize {
+        if n == 0 {
+            return 0;
+        }
+        let lines = n.div_ceil(self.columns);
+        let end = end_offset(self.offset, n).expect("offset overflow");
+
+        let mut total = offset_widths(self.offset, self.columns as u64, lines);
+        total += lines * self.line_overhead();
+        total += n;
+        if self.style == Style::Canonical {
+            total += offset_width(end) + 1;
+        }
+        total
+    }
+
+    /// Formats bytes to a hexdump.
+    ///
+    /// # Errors
+    /// This function returns `Err` if
+    /// + the length of `dst` is not enough.
+    /// + the offsets overflow.
+    #[inline]
+    pub fn dump<'d>(&self, src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+        ensure!(end_offset(self.offset, src.len()).is_some());
+        let m = self.dumped_length(src.len());
+        ensure!(dst.len() >= m);
+        unsafe {
+            let dst = dst.as_mut_ptr();
+            self.dump_raw(src, dst);
+            Ok(slice_mut(dst, m))
+        }
+    }
+
+    /// Formats bytes to a hexdump and returns [`&mut str`](str).
+    ///
+    /// # Errors
+    /// This function returns `Err` if
+    /// + the length of `dst` is not enough.
+    /// + the offsets overflow.
+    #[inline]
+    pub fn dump_as_str<'d>(&self, src: &[u8], dst: Out<'d, [u8]>) -> Result<&'d mut str, Error> {
+        let ans = self.dump(src, dst)?;
+        Ok(unsafe { core::str::from_utf8_unchecked_mut(ans) })
+    }
+
+    /// Formats bytes to a hexdump.
+    ///
+    /// # Panics
+    /// This function panics if the offsets overflow.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use]
+    pub fn dump_to_string(&self, data: impl AsRef<[u8]>) -> String {
+        let src = data.as_ref();
+        let m = self.dumped_length(src.len());
+        let mut buf: Vec<u8> = Vec::with_capacity(m);
+        unsafe {
+            self.dump_raw(src, buf.as_mut_ptr());
+            buf.set_len(m);
+            String::from_utf8_unchecked(buf)
+        }
+    }
+
+    /// Parses a hexdump to bytes (`xxd -r`).
+    ///
+    /// The offset column must be contiguous, starting at the configured offset.
+    /// The ascii column is ignored.
+    ///
+    /// # Errors
+    /// This function returns `Err` if
+    /// + the length of `dst` is not enough.
+    /// + the content of `src` is invalid.
+    #[inline]
+    pub fn parse<'d>(&self, src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+        let cap = dst.len();
+        let dst = dst.as_mut_ptr();
+        let mut len = 0;
+        self.parse_lines(src, |hex| {
+            let m = hex.len() / 2;
+            ensure!(cap - len >= m);
+            unsafe {
+                crate::decode_raw(hex.as_ptr(), hex.len(), dst.add(len))?;
+            }
+            len += m;
+            Ok(())
+        })?;
+        Ok(unsafe { slice_mut(dst, len) })
+    }
+
+    /// Parses a hexdump to bytes (`xxd -r`).
+    ///
+    /// # Errors
+    /// This function returns `Err` if the content of `data` is invalid.
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn parse_to_vec(&self, data: impl AsRef<[u8]>) -> Result<Vec<u8>, Error> {
+        let mut buf = Vec::new();
+        self.parse_lines(data.as_ref(), |hex| crate::decode_append(hex, &mut buf))?;
+        Ok(buf)
+    }
+
+    unsafe fn dump_raw(&self, src: &[u8], mut dst: *mut u8) {
+        let columns = self.columns;
+        let group = self.group_size();
+        let hex_width = self.hex_width();
+
+        // whole lines of a block are encoded by one kernel call
+        let mut hex = [0u8; BLOCK_SIZE * 2];
+        let block_size = BLOCK_SIZE / columns * columns;
+        let mut offset = self.offset;
+
+        for block in src.chunks(block_size) {
+            crate::multiversion::encode::auto(block.as_ptr(), block.len(), hex.as_mut_ptr(), self.case);
+
+            for (line, line_hex) in block.chunks(columns).zip(hex.chunks(columns * 2)) {
+                let k = line.len();
+                let line_hex = &line_hex[..k * 2];
+
+                dst = write_offset(dst, offset);
+                offset += k as u64;
+
+                let sep: &[u8; 2] = match self.style {
+                    Style::Xxd => b": ",
+                    Style::Canonical => b"  ",
+                };
+                copy_nonoverlapping(sep.as_ptr(), dst, 2);
+                dst = dst.add(2);
+
+                let hex_area = dst;
+                match self.style {
+                    Style::Xxd => {
+                        for chunk in line_hex.chunks(group * 2) {
+                            copy_nonoverlapping(chunk.as_ptr(), dst, chunk.len());
+                            dst = dst.add(chunk.len());
+                            dst.write(b' ');
+                            dst = dst.add(1);
+                        }
+                    }
+                    Style::Canonical => {
+                        for (i, pair) in line_hex.chunks_exact(2).enumerate() {
+                            copy_nonoverlapping(pair.as_ptr(), dst, 2);
+                            dst.add(2).write(b' ');
+                            dst = dst.add(3);
+                            if (i + 1) % group == 0 && i + 1 < columns {
+                                dst.write(b' ');
+                                dst = dst.add(1);
+                            }
+                        }
+                    }
+                }
+                let written = dst.offset_from(hex_area) as usize;
+                write_bytes(dst, b' ', hex_width - written);
+                dst = hex_area.add(hex_width);
+
+                match self.style {
+                    Style::Xxd => {
+                        dst.write(b' ');
+                        dst = dst.add(1);
+                        dst = write_ascii(dst, line);
+                    }
+                    Style::Canonical => {
+                        copy_nonoverlapping(b" |".as_ptr(), dst, 2);
+                        dst = write_ascii(dst.add(2), line);
+                        dst.write(b'|');
+                        dst = dst.add(1);
+                    }
+                }
+
+                dst.write(b'\n');
+                dst = dst.add(1);
+            }
+        }
+
+        if self.style == Style::Canonical && !src.is_empty() {
+            dst = write_offset(dst, offset);
+            dst.write(b'\n');
+        }
+    }
+
+    fn parse_lines(&self, src: &[u8], mut f: impl FnMut(&[u8]) -> Result<(), Error>) -> Result<(), Error> {
+        let mut hex = [0u8; MAX_COLUMNS * 2];
+
+        let mut expected = self.offset;
+        let mut finished = false;
+
+        for line in src.split(|&c| c == b'\n') {
+            if line.is_empty() {
+                continue;
+            }
+            ensure!(!finished);
+
+            let (offset, rest) = parse_offset(line)?;
+            ensure!(offset == expected);
+
+            if rest.is_empty() && self.style == Style::Canonical {
+                finished = true;
+                continue;
+            }
+
+            let sep: &[u8] = match self.style {
+                Style::Xxd => b": ",
+                Style::Canonical => b"  ",
+            };
+            let rest = rest.strip_prefix(sep).ok_or_else(Error::new)?;
+            let area = hex_area(rest, self.style);
+
+            let mut n = 0;
+            for &c in area {
+                if c != b' ' {
+                    ensure!(n < hex.len());
+                    hex[n] = c;
+                    n += 1;
+                }
+            }
+            ensure!(n > 0 && n % 2 == 0);
+
+            f(&hex[..n])?;
+            expected = end_offset(expected, n / 2).ok_or_else(Error::new)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits the hex groups from the ascii column.
+///
+/// The hex area ends at the first double space in `xxd` lines, or at the first `|` in canonical lines,
+/// so short lines are accepted with or without padding, like `xxd -r`.
+#[inline(always)]
+fn hex_area(rest: &[u8], style: Style) -> &[u8] {
+    let end = match style {
+        Style::Xxd => rest.windows(2).position(|w| w == b"  "),
+        Style::Canonical => rest.iter().position(|&c| c == b'|'),
+    };
+    &rest[..end.unwrap_or(rest.len())]
+}
+
+#[inline(always)]
+fn end_offset(offset: u64, n: usize) -> Option<u64> {
+    offset.checked_add(u64::try_from(n).ok()?)
+}
+
+#[inline(always)]
+const fn offset_width(offset: u64) -> usize {
+    let digits = (64 - offset.leading_zeros() as usize).div_ceil(4);
+    if digits > 8 {
+        digits
+    } else {
+        8
+    }
+}
+
+/// Sums the offset widths of `lines` lines which start at `offset` and advance by `step`.
+fn offset_widths(mut offset: u64, step: u64, mut lines: usize) -> usize {
+    let mut total = 0;
+    while lines > 0 {
+        let width = offset_width(offset);
+        let count = if width >= 16 {
+            lines
+        } else {
+            let limit: u64 = 1 << (width * 4);
+            let count = (limit - offset).div_ceil(step);
+            count.min(lines as u64) as usize
+        };
+        total += count * width;
+        lines -= count;
+        offset = offset.saturating_add(count as u64 * step);
+    }
+    total
+}
+
+#[inline(always)]
+unsafe fn write_offset(dst: *mut u8, offset: u64) -> *mut u8 {
+    let charset = vsimd::hex::LOWER_CHARSET;
+    let width = offset_width(offset);
+    let mut i = 0;
+    while i < width {
+        let nibble = (offset >> (i * 4)) & 0x0f;
+        write(dst, width - 1 - i, charset[nibble as usize]);
+        i += 1;
+    }
+    dst.add(width)
+}
+
+#[inline(always)]
+unsafe fn write_ascii(dst: *mut u8, line: &[u8]) -> *mut u8 {
+    // a branchless map over slices, which the compiler vectorizes
+    let out = slice_mut(dst, line.len());
+    for (d, &c) in out.iter_mut().zip(line) {
+        let printable = matches!(c, 0x20..=0x7e);
+        *d = if printable { c } else { b'.' };
+    }
+    dst.add(line.len())
+}
+
+fn parse_offset(line: &[u8]) -> Result<(u64, &[u8]), Error> {
+    let digits = line.iter().take_while(|c| c.is_ascii_hexdigit()).count();
+    ensure!(digits > 0 && digits <= 16);
+
+    let mut offset: u64 = 0;
+    for &c in &line[..digits] {
+        offset = (offset << 4) | u64::from(vsimd::hex::unhex(c));
+    }
+    Ok((offset, &line[digits..]))
+}
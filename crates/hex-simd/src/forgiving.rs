@@ -0,0 +1,101 @@
+use crate::{AsOut, Error, Out};
+
+use vsimd::tools::slice_mut;
+
+#[cfg(all(feature = "alloc", not(any(test, feature = "std"))))]
+use alloc::vec::Vec;
+
+const BLOCK_SIZE: usize = 256;
+
+/// Skips leading ascii whitespace and an optional `0x` or `0X` prefix.
+#[inline(always)]
+fn prefix_length(data: &[u8]) -> usize {
+    let mut i = 0;
+    while i < data.len() && data[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    if let Some([b'0', b'x' | b'X']) = data.get(i..i + 2) {
+        i += 2;
+    }
+    i
+}
+
+/// Forgiving decodes a hex string to bytes case-insensitively and writes inplace.
+///
+/// This function accepts an optional `0x` or `0X` prefix and ignores ascii whitespace.
+///
+/// # Errors
+/// This function returns `Err` if the content of `data` is invalid.
+#[inline]
+pub fn forgiving_decode_inplace(data: &mut [u8]) -> Result<&mut [u8], Error> {
+    let start = prefix_length(data);
+    unsafe {
+        let len = data.len() - start;
+        let dst = data.as_mut_ptr();
+        let src = dst.add(start);
+
+        let n = crate::multiversion::remove_ascii_whitespace::auto(src, len, dst);
+        ensure!(n % 2 == 0);
+
+        crate::multiversion::decode::auto(dst, n, dst)?;
+        Ok(slice_mut(dst, n / 2))
+    }
+}
+
+/// Forgiving decodes a hex string to bytes case-insensitively.
+///
+/// This function accepts an optional `0x` or `0X` prefix and ignores ascii whitespace.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn forgiving_decode<'d>(src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    let src = &src[prefix_length(src)..];
+
+    let cap = dst.len();
+    let dst = dst.as_mut_ptr();
+    let mut len = 0;
+
+    let mut buf = [0u8; BLOCK_SIZE + 1];
+    let mut pending = 0;
+
+    for chunk in src.chunks(BLOCK_SIZE) {
+        unsafe {
+            let buf = buf.as_mut_ptr();
+            let n = pending
+                + crate::multiversion::remove_ascii_whitespace::auto(chunk.as_ptr(), chunk.len(), buf.add(pending));
+
+            let m = n / 2;
+            ensure!(cap - len >= m);
+            crate::multiversion::decode::auto(buf, m * 2, dst.add(len))?;
+            len += m;
+
+            pending = n % 2;
+            if pending != 0 {
+                buf.write(buf.add(n - 1).read());
+            }
+        }
+    }
+    ensure!(pending == 0);
+
+    Ok(unsafe { slice_mut(dst, len) })
+}
+
+/// Forgiving decodes a hex string to bytes case-insensitively and returns a new [`Vec<u8>`](Vec).
+///
+/// This function accepts an optional `0x` or `0X` prefix and ignores ascii whitespace.
+///
+/// # Errors
+/// This function returns `Err` if the content of `data` is invalid.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn forgiving_decode_to_vec(data: impl AsRef<[u8]>) -> Result<Vec<u8>, Error> {
+    let data = data.as_ref();
+    let mut vec = Vec::with_capacity(data.len() / 2);
+    let n = forgiving_decode(data, vec.spare_capacity_mut().as_out())?.len();
+    unsafe { vec.set_len(n) };
+    Ok(vec)
+}
@@ -0,0 +1,146 @@
+use crate::{AsciiCase, Error};
+
+use vsimd::is_isa_type;
+use vsimd::isa::{InstructionSet, SSE2};
+use vsimd::vector::{V128, V64};
+use vsimd::{SIMD128, SIMD256};
+
+#[inline(always)]
+pub unsafe fn encode16_fallback(src: *const u8, dst: *mut u8, case: AsciiCase) {
+    crate::encode::encode_fallback(src, 16, dst, case);
+}
+
+#[inline(always)]
+pub unsafe fn encode16_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8, case: AsciiCase) {
+    if is_isa_type!(S, SSE2) {
+        return encode16_simd_sse2(SSE2::new(), src, dst, case);
+    }
+    {
+        let lut = match case {
+            AsciiCase::Lower => vsimd::hex::ENCODE_LOWER_LUT,
+            AsciiCase::Upper => vsimd::hex::ENCODE_UPPER_LUT,
+        };
+        let x = s.v128_load_unaligned(src);
+        let y = vsimd::hex::encode_bytes16(s, x, lut);
+        s.v256_store_unaligned(dst, y);
+    }
+}
+
+#[inline(always)]
+unsafe fn encode16_simd_sse2(s: SSE2, src: *const u8, dst: *mut u8, case: AsciiCase) {
+    let offset = match case {
+        AsciiCase::Lower => vsimd::hex::sse2::LOWER_OFFSET,
+        AsciiCase::Upper => vsimd::hex::sse2::UPPER_OFFSET,
+    };
+
+    let x = s.v128_load_unaligned(src);
+    let (y1, y2) = vsimd::hex::sse2::encode16(s, x, offset);
+
+    s.v128_store_unaligned(dst, y1);
+    s.v128_store_unaligned(dst.add(16), y2);
+}
+
+#[inline(always)]
+pub unsafe fn encode4_fallback(src: *const u8, dst: *mut u8, case: AsciiCase) {
+    crate::encode::encode_fallback(src, 4, dst, case);
+}
+
+#[inline(always)]
+pub unsafe fn encode4_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8, case: AsciiCase) {
+    encode_short_simd::<S, 4>(s, src, dst, case);
+}
+
+#[inline(always)]
+pub unsafe fn encode8_fallback(src: *const u8, dst: *mut u8, case: AsciiCase) {
+    crate::encode::encode_fallback(src, 8, dst, case);
+}
+
+#[inline(always)]
+pub unsafe fn encode8_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8, case: AsciiCase) {
+    encode_short_simd::<S, 8>(s, src, dst, case);
+}
+
+/// Encodes `N <= 8` bytes in the low half of a vector.
+#[inline(always)]
+unsafe fn encode_short_simd<S: SIMD256, const N: usize>(s: S, src: *const u8, dst: *mut u8, case: AsciiCase) {
+    let mut buf = [0u8; 16];
+    buf.as_mut_ptr().copy_from_nonoverlapping(src, N);
+    let x = s.v128_load_unaligned(buf.as_ptr());
+
+    let y = if is_isa_type!(S, SSE2) {
+        let offset = match case {
+            AsciiCase::Lower => vsimd::hex::sse2::LOWER_OFFSET,
+            AsciiCase::Upper => vsimd::hex::sse2::UPPER_OFFSET,
+        };
+        vsimd::hex::sse2::encode16(SSE2::new(), x, offset).0
+    } else {
+        let lut = match case {
+            AsciiCase::Lower => vsimd::hex::ENCODE_LOWER_LUT,
+            AsciiCase::Upper => vsimd::hex::ENCODE_UPPER_LUT,
+        };
+        vsimd::hex::encode_bytes16(s, x, lut).to_v128x2().0
+    };
+
+    s.v128_store_unaligned(buf.as_mut_ptr(), y);
+    dst.copy_from_nonoverlapping(buf.as_ptr(), N * 2);
+}
+
+#[inline(always)]
+pub unsafe fn decode16_fallback(src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    crate::decode::decode_fallback(src, 16, dst)
+}
+
+#[inline(always)]
+unsafe fn decode_v128<S: SIMD256>(s: S, x: V128) -> Result<V64, Error> {
+    if is_isa_type!(S, SSE2) {
+        let s = SSE2::new();
+        let (nibbles, flag) = vsimd::hex::sse2::decode_nibbles(s, x);
+        ensure!(s.u8x16_bitmask(flag) == 0);
+        Ok(vsimd::hex::sse2::merge_bits(s, nibbles))
+    } else {
+        Ok(try_!(vsimd::hex::decode_ascii16(s, x)))
+    }
+}
+
+#[inline(always)]
+pub unsafe fn decode16_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    let x = s.v128_load_unaligned(src);
+    let y = decode_v128(s, x)?;
+    dst.cast::<V64>().write_unaligned(y);
+    Ok(())
+}
+
+#[inline(always)]
+pub unsafe fn decode8_fallback(src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    crate::decode::decode_fallback(src, 8, dst)
+}
+
+/// Decodes 8 characters in the low half of a vector, the high half is padded with `0`.
+#[inline(always)]
+pub unsafe fn decode8_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    let mut buf = [b'0'; 16];
+    buf.as_mut_ptr().copy_from_nonoverlapping(src, 8);
+    let x = s.v128_load_unaligned(buf.as_ptr());
+    let y = decode_v128(s, x)?;
+    dst.copy_from_nonoverlapping(y.as_bytes().as_ptr(), 4);
+    Ok(())
+}
+
+#[inline(always)]
+pub unsafe fn decode32_fallback(src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    crate::decode::decode_fallback(src, 32, dst)
+}
+
+#[inline(always)]
+pub unsafe fn decode32_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    if is_isa_type!(S, SSE2) {
+        decode16_simd(s, src, dst)?;
+        return decode16_simd(s, src.add(16), dst.add(8));
+    }
+    {
+        let x = s.v256_load_unaligned(src);
+        let y = try_!(vsimd::hex::decode_ascii32(s, x));
+        s.v128_store_unaligned(dst, y);
+        Ok(())
+    }
+}
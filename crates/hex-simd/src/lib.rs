@@ -49,15 +49,30 @@ extern crate alloc;
 mod error;
 pub use self::error::Error;
 
+mod ascii;
 mod check;
 mod decode;
 mod encode;
+mod int;
+mod reversed;
+mod utf16;
 
 mod multiversion;
 
 #[cfg(feature = "alloc")]
 mod heap;
 
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use self::parallel::*;
+
+pub mod array;
+pub mod dump;
+
+mod forgiving;
+pub use self::forgiving::*;
+
 pub use outref::{AsOut, Out};
 pub use vsimd::ascii::AsciiCase;
 
@@ -147,6 +162,18 @@ pub fn check(data: &[u8]) -> Result<(), Error> {
     unsafe { crate::multiversion::check::auto(src, len) }
 }
 
+/// Checks whether `data` is a hex string in the specified ascii case.
+///
+/// Digits are accepted in both cases. Letters in the other case are rejected.
+///
+/// # Errors
+/// This function returns `Err` if any byte in `data` is not a hex character of `case`.
+#[inline]
+pub fn check_case(data: &[u8], case: AsciiCase) -> Result<(), Error> {
+    let (src, len) = slice_parts(data);
+    unsafe { crate::multiversion::check_case::auto(src, len, case) }
+}
+
 /// Encodes bytes to a hex string.
 ///
 /// `case` specifies the ascii case of output.
@@ -183,6 +210,25 @@ pub fn decode<'d>(src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Er
     }
 }
 
+/// Decodes a hex string to bytes, accepting letters in the specified ascii case only.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid or contains letters in the other case.
+#[inline]
+pub fn decode_case<'d>(src: &[u8], mut dst: Out<'d, [u8]>, case: AsciiCase) -> Result<&'d mut [u8], Error> {
+    ensure!(src.len() % 2 == 0 && dst.len() >= src.len() / 2);
+
+    let len = src.len();
+    let dst = dst.as_mut_ptr();
+    let src = src.as_ptr();
+    unsafe {
+        crate::multiversion::decode_case::auto(src, len, dst, case)?;
+        Ok(slice_mut(dst, len / 2))
+    }
+}
+
 /// Decodes a hex string to bytes case-insensitively and writes inplace.
 ///
 /// # Errors
@@ -199,6 +245,79 @@ pub fn decode_inplace(data: &mut [u8]) -> Result<&mut [u8], Error> {
     }
 }
 
+/// Encodes bytes to a hex string in reversed byte order.
+///
+/// The last byte of `src` is encoded first, as in Bitcoin transaction ids or little-endian dumps.
+///
+/// # Errors
+/// This function returns `Err` if the length of `dst` is not enough.
+#[inline]
+pub fn encode_reversed<'d>(src: &[u8], mut dst: Out<'d, [u8]>, case: AsciiCase) -> Result<&'d mut [u8], Error> {
+    ensure!(dst.len() / 2 >= src.len());
+    unsafe {
+        let (src, len) = slice_parts(src);
+        let dst = dst.as_mut_ptr();
+        crate::multiversion::encode_reversed::auto(src, len, dst, case);
+        Ok(slice_mut(dst, len * 2))
+    }
+}
+
+/// Decodes a hex string to bytes case-insensitively in reversed byte order.
+///
+/// The first byte in `src` becomes the last byte of output.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid.
+#[inline]
+pub fn decode_reversed<'d>(src: &[u8], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    ensure!(src.len() % 2 == 0 && dst.len() >= src.len() / 2);
+
+    let len = src.len();
+    let dst = dst.as_mut_ptr();
+    let src = src.as_ptr();
+    unsafe {
+        crate::multiversion::decode_reversed::auto(src, len, dst)?;
+        Ok(slice_mut(dst, len / 2))
+    }
+}
+
+/// Encodes bytes to a hex string in UTF-16 code units.
+///
+/// `case` specifies the ascii case of output.
+///
+/// # Errors
+/// This function returns `Err` if the length of `dst` is not enough.
+#[inline]
+pub fn encode_utf16<'d>(src: &[u8], mut dst: Out<'d, [u16]>, case: AsciiCase) -> Result<&'d mut [u16], Error> {
+    ensure!(dst.len() / 2 >= src.len());
+    unsafe {
+        let (src, len) = slice_parts(src);
+        let dst = dst.as_mut_ptr();
+        crate::multiversion::encode_utf16::auto(src, len, dst, case);
+        Ok(slice_mut(dst, len * 2))
+    }
+}
+
+/// Decodes a hex string in UTF-16 code units to bytes case-insensitively.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid, including any non-ascii code unit.
+#[inline]
+pub fn decode_utf16<'d>(src: &[u16], mut dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    ensure!(src.len() % 2 == 0 && dst.len() >= src.len() / 2);
+
+    let (src, len) = slice_parts(src);
+    let dst = dst.as_mut_ptr();
+    unsafe {
+        crate::multiversion::decode_utf16::auto(src, len, dst)?;
+        Ok(slice_mut(dst, len / 2))
+    }
+}
+
 /// Encodes bytes to a hex string and returns [`&mut str`](str).
 ///
 /// `case` specifies the ascii case of output.
@@ -292,3 +411,120 @@ pub fn encode_to_string(data: impl AsRef<[u8]>, case: AsciiCase) -> String {
 pub fn decode_to_vec(data: impl AsRef<[u8]>) -> Result<Vec<u8>, Error> {
     decode_type(data)
 }
+
+// -------------------------------------------------------------------------------------------------
+
+/// Formats a `u32` as a zero-padded hex string of 8 characters.
+#[inline]
+#[must_use]
+pub fn format_u32(x: u32, case: AsciiCase) -> [u8; 8] {
+    let src = x.to_be_bytes();
+    let mut dst = [0; 8];
+    unsafe { crate::multiversion::encode4::auto(src.as_ptr(), dst.as_mut_ptr(), case) };
+    dst
+}
+
+/// Formats a `u64` as a zero-padded hex string of 16 characters.
+#[inline]
+#[must_use]
+pub fn format_u64(x: u64, case: AsciiCase) -> [u8; 16] {
+    let src = x.to_be_bytes();
+    let mut dst = [0; 16];
+    unsafe { crate::multiversion::encode8::auto(src.as_ptr(), dst.as_mut_ptr(), case) };
+    dst
+}
+
+/// Formats a `u128` as a zero-padded hex string of 32 characters.
+#[inline]
+#[must_use]
+pub fn format_u128(x: u128, case: AsciiCase) -> [u8; 32] {
+    let src = x.to_be_bytes();
+    let mut dst = [0; 32];
+    unsafe { crate::multiversion::encode16::auto(src.as_ptr(), dst.as_mut_ptr(), case) };
+    dst
+}
+
+/// Parses a `u32` from a hex string of 8 characters case-insensitively.
+///
+/// # Errors
+/// This function returns `Err` if the content of `src` is invalid.
+#[inline]
+pub fn parse_u32(src: &[u8; 8]) -> Result<u32, Error> {
+    let mut dst = [0; 4];
+    unsafe { crate::multiversion::decode8::auto(src.as_ptr(), dst.as_mut_ptr())? };
+    Ok(u32::from_be_bytes(dst))
+}
+
+/// Parses a `u64` from a hex string of 16 characters case-insensitively.
+///
+/// # Errors
+/// This function returns `Err` if the content of `src` is invalid.
+#[inline]
+pub fn parse_u64(src: &[u8; 16]) -> Result<u64, Error> {
+    let mut dst = [0; 8];
+    unsafe { crate::multiversion::decode16::auto(src.as_ptr(), dst.as_mut_ptr())? };
+    Ok(u64::from_be_bytes(dst))
+}
+
+/// Parses a `u128` from a hex string of 32 characters case-insensitively.
+///
+/// # Errors
+/// This function returns `Err` if the content of `src` is invalid.
+#[inline]
+pub fn parse_u128(src: &[u8; 32]) -> Result<u128, Error> {
+    let mut dst = [0; 16];
+    unsafe { crate::multiversion::decode32::auto(src.as_ptr(), dst.as_mut_ptr())? };
+    Ok(u128::from_be_bytes(dst))
+}
+
+/// Formats an [OpenTelemetry] trace id as 32 lowercase hex characters.
+///
+/// [OpenTelemetry]: https://www.w3.org/TR/trace-context/#trace-id
+#[inline]
+#[must_use]
+pub fn format_trace_id(id: &[u8; 16]) -> [u8; 32] {
+    let mut dst = [0; 32];
+    unsafe { crate::multiversion::encode16::auto(id.as_ptr(), dst.as_mut_ptr(), AsciiCase::Lower) };
+    dst
+}
+
+/// Formats an [OpenTelemetry] span id as 16 lowercase hex characters.
+///
+/// [OpenTelemetry]: https://www.w3.org/TR/trace-context/#parent-id
+#[inline]
+#[must_use]
+pub fn format_span_id(id: &[u8; 8]) -> [u8; 16] {
+    format_u64(u64::from_be_bytes(*id), AsciiCase::Lower)
+}
+
+/// Parses an [OpenTelemetry] trace id from 32 lowercase hex characters.
+///
+/// [OpenTelemetry]: https://www.w3.org/TR/trace-context/#trace-id
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the content of `src` is not lowercase hex.
+/// + the trace id is all zeroes, which is invalid.
+#[inline]
+pub fn parse_trace_id(src: &[u8; 32]) -> Result<[u8; 16], Error> {
+    let mut dst = [0; 16];
+    unsafe { crate::multiversion::decode_case::auto(src.as_ptr(), 32, dst.as_mut_ptr(), AsciiCase::Lower)? };
+    ensure!(dst != [0; 16]);
+    Ok(dst)
+}
+
+/// Parses an [OpenTelemetry] span id from 16 lowercase hex characters.
+///
+/// [OpenTelemetry]: https://www.w3.org/TR/trace-context/#parent-id
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the content of `src` is not lowercase hex.
+/// + the span id is all zeroes, which is invalid.
+#[inline]
+pub fn parse_span_id(src: &[u8; 16]) -> Result<[u8; 8], Error> {
+    let mut dst = [0; 8];
+    unsafe { crate::multiversion::decode_case::auto(src.as_ptr(), 16, dst.as_mut_ptr(), AsciiCase::Lower)? };
+    ensure!(dst != [0; 8]);
+    Ok(dst)
+}
@@ -11,6 +11,15 @@ vsimd::dispatch!(
     fastest     = {"avx2", "neon", "simd128"},
 );
 
+vsimd::dispatch!(
+    name        = {check_case},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, case: AsciiCase) -> Result<(), Error>},
+    fallback    = {crate::check::check_case_fallback},
+    simd        = {crate::check::check_case_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
 vsimd::dispatch!(
     name        = {encode},
     signature   = {pub unsafe fn(src: *const u8, len: usize, dst: *mut u8, case: AsciiCase) -> () },
@@ -28,3 +37,111 @@ vsimd::dispatch!(
     targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
     fastest     = {"avx2", "neon", "simd128"},
 );
+
+vsimd::dispatch!(
+    name        = {decode_case},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, dst: *mut u8, case: AsciiCase) -> Result<(), Error>},
+    fallback    = {crate::decode::decode_case_fallback},
+    simd        = {crate::decode::decode_case_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {remove_ascii_whitespace},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, dst: *mut u8) -> usize},
+    fallback    = {crate::ascii::remove_ascii_whitespace_fallback},
+    simd        = {crate::ascii::remove_ascii_whitespace_simd},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {encode4},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8, case: AsciiCase) -> ()},
+    fallback    = {crate::int::encode4_fallback},
+    simd        = {crate::int::encode4_simd},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {encode8},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8, case: AsciiCase) -> ()},
+    fallback    = {crate::int::encode8_fallback},
+    simd        = {crate::int::encode8_simd},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {encode16},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8, case: AsciiCase) -> ()},
+    fallback    = {crate::int::encode16_fallback},
+    simd        = {crate::int::encode16_simd},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {decode8},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8) -> Result<(), Error>},
+    fallback    = {crate::int::decode8_fallback},
+    simd        = {crate::int::decode8_simd},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {decode16},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8) -> Result<(), Error>},
+    fallback    = {crate::int::decode16_fallback},
+    simd        = {crate::int::decode16_simd},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {decode32},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8) -> Result<(), Error>},
+    fallback    = {crate::int::decode32_fallback},
+    simd        = {crate::int::decode32_simd},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {encode_reversed},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, dst: *mut u8, case: AsciiCase) -> () },
+    fallback    = {crate::reversed::encode_reversed_fallback},
+    simd        = {crate::reversed::encode_reversed_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {decode_reversed},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, dst: *mut u8) -> Result<(), Error>},
+    fallback    = {crate::reversed::decode_reversed_fallback},
+    simd        = {crate::reversed::decode_reversed_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {encode_utf16},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, dst: *mut u16, case: AsciiCase) -> () },
+    fallback    = {crate::utf16::encode_utf16_fallback},
+    simd        = {crate::utf16::encode_utf16_simd},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {decode_utf16},
+    signature   = {pub unsafe fn(src: *const u16, len: usize, dst: *mut u8) -> Result<(), Error>},
+    fallback    = {crate::utf16::decode_utf16_fallback},
+    simd        = {crate::utf16::decode_utf16_simd},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
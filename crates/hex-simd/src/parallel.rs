@@ -0,0 +1,106 @@
+use crate::{AsciiCase, Error, Out};
+
+use rayon::prelude::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+use vsimd::tools::slice_mut;
+
+/// Returns the number of bytes per chunk, or `None` if `len` is too short to be worth splitting.
+#[inline(always)]
+fn chunk_size(len: usize) -> Option<usize> {
+    let p = rayon::current_num_threads();
+    if len < p * 4096 || p < 2 {
+        return None;
+    }
+    Some(len.div_ceil(p))
+}
+
+/// **EXPERIMENTAL**:
+/// Encodes bytes to a hex string in parallel.
+///
+/// `case` specifies the ascii case of output.
+///
+/// # Errors
+/// This function returns `Err` if the length of `dst` is not enough.
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+#[inline]
+pub fn par_encode<'d>(src: &[u8], dst: Out<'d, [u8]>, case: AsciiCase) -> Result<&'d mut [u8], Error> {
+    let Some(chunk) = chunk_size(src.len()) else {
+        return crate::encode(src, dst, case);
+    };
+
+    ensure!(dst.len() / 2 >= src.len());
+
+    let dst = unsafe { dst.into_uninit_slice() };
+    let dst = &mut dst[..src.len() * 2];
+
+    let src_chunks = src.par_chunks(chunk);
+    let dst_chunks = dst.par_chunks_mut(chunk * 2);
+
+    src_chunks.zip(dst_chunks).for_each(|(s, d)| unsafe {
+        let len = s.len();
+        let sp = s.as_ptr();
+        let dp = d.as_mut_ptr().cast::<u8>();
+        crate::multiversion::encode::auto(sp, len, dp, case);
+    });
+
+    unsafe {
+        let len = dst.len();
+        let ptr = dst.as_mut_ptr().cast::<u8>();
+        Ok(slice_mut(ptr, len))
+    }
+}
+
+/// **EXPERIMENTAL**:
+/// Decodes a hex string to bytes case-insensitively in parallel.
+///
+/// # Errors
+/// This function returns `Err` if
+/// + the length of `dst` is not enough.
+/// + the content of `src` is invalid.
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+#[inline]
+pub fn par_decode<'d>(src: &[u8], dst: Out<'d, [u8]>) -> Result<&'d mut [u8], Error> {
+    let Some(chunk) = chunk_size(src.len() / 2) else {
+        return crate::decode(src, dst);
+    };
+
+    ensure!(src.len() % 2 == 0 && dst.len() >= src.len() / 2);
+
+    let dst = unsafe { dst.into_uninit_slice() };
+    let dst = &mut dst[..src.len() / 2];
+
+    let src_chunks = src.par_chunks(chunk * 2);
+    let dst_chunks = dst.par_chunks_mut(chunk);
+
+    src_chunks.zip(dst_chunks).try_for_each(|(s, d)| unsafe {
+        let len = s.len();
+        let sp = s.as_ptr();
+        let dp = d.as_mut_ptr().cast::<u8>();
+        crate::multiversion::decode::auto(sp, len, dp)
+    })?;
+
+    unsafe {
+        let len = dst.len();
+        let ptr = dst.as_mut_ptr().cast::<u8>();
+        Ok(slice_mut(ptr, len))
+    }
+}
+
+/// **EXPERIMENTAL**:
+/// Checks whether `data` is a hex string in parallel.
+///
+/// # Errors
+/// This function returns `Err` if any byte in `data` is not a hex character.
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+#[inline]
+pub fn par_check(data: &[u8]) -> Result<(), Error> {
+    let Some(chunk) = chunk_size(data.len()) else {
+        return crate::check(data);
+    };
+
+    data.par_chunks(chunk).try_for_each(|s| unsafe {
+        let len = s.len();
+        let sp = s.as_ptr();
+        crate::multiversion::check::auto(sp, len)
+    })
+}
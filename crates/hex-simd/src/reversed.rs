@@ -0,0 +1,86 @@
+use crate::{AsciiCase, Error};
+
+use vsimd::hex::unhex;
+use vsimd::tools::read;
+use vsimd::vector::V128;
+use vsimd::SIMD256;
+
+const REVERSE: V128 = V128::from_bytes([
+    0x0f, 0x0e, 0x0d, 0x0c, 0x0b, 0x0a, 0x09, 0x08, //
+    0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x00, //
+]);
+
+#[inline(always)]
+pub unsafe fn encode_reversed_fallback(src: *const u8, len: usize, mut dst: *mut u8, case: AsciiCase) {
+    let charset = match case {
+        AsciiCase::Lower => vsimd::hex::LOWER_CHARSET,
+        AsciiCase::Upper => vsimd::hex::UPPER_CHARSET,
+    }
+    .as_ptr();
+
+    let mut end = src.add(len);
+    while end > src {
+        end = end.sub(1);
+        let x = end.read();
+        dst.write(read(charset, (x >> 4) as usize));
+        dst.add(1).write(read(charset, (x & 0x0f) as usize));
+        dst = dst.add(2);
+    }
+}
+
+#[inline(always)]
+pub unsafe fn encode_reversed_simd<S: SIMD256>(s: S, src: *const u8, len: usize, mut dst: *mut u8, case: AsciiCase) {
+    let lut = match case {
+        AsciiCase::Lower => vsimd::hex::ENCODE_LOWER_LUT,
+        AsciiCase::Upper => vsimd::hex::ENCODE_UPPER_LUT,
+    };
+
+    let mut end = src.add(len);
+    let stop = src.add(len % 16);
+    while end > stop {
+        end = end.sub(16);
+        let x = s.u8x16_swizzle(s.v128_load_unaligned(end), REVERSE);
+        let y = vsimd::hex::encode_bytes16(s, x, lut);
+        s.v256_store_unaligned(dst, y);
+        dst = dst.add(32);
+    }
+
+    encode_reversed_fallback(src, len % 16, dst, case);
+}
+
+#[inline(always)]
+pub unsafe fn decode_reversed_fallback(mut src: *const u8, len: usize, dst: *mut u8) -> Result<(), Error> {
+    let end = src.add(len);
+    let mut out = dst.add(len / 2);
+    let mut flag = 0;
+    while src < end {
+        let y1 = unhex(read(src, 0));
+        let y2 = unhex(read(src, 1));
+        flag |= y1 | y2;
+        out = out.sub(1);
+        out.write(y1.wrapping_shl(4) | y2);
+        src = src.add(2);
+    }
+    ensure!(flag != 0xff);
+    Ok(())
+}
+
+#[inline(always)]
+pub unsafe fn decode_reversed_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u8,
+    len: usize,
+    dst: *mut u8,
+) -> Result<(), Error> {
+    let mut out = dst.add(len / 2);
+    let end = src.add(len / 32 * 32);
+    while src < end {
+        let x = s.v256_load_unaligned(src);
+        let y = try_!(vsimd::hex::decode_ascii32(s, x));
+        out = out.sub(16);
+        s.v128_store_unaligned(out, s.u8x16_swizzle(y, REVERSE));
+        src = src.add(32);
+    }
+
+    decode_reversed_fallback(src, len % 32, dst)
+}
@@ -0,0 +1,132 @@
+use crate::{AsciiCase, Error};
+
+use vsimd::hex::unhex;
+use vsimd::is_isa_type;
+use vsimd::isa::{InstructionSet, SSE2};
+use vsimd::mask::u8x32_highbit_any;
+use vsimd::vector::V64;
+use vsimd::{SIMD128, SIMD256};
+
+#[inline(always)]
+pub unsafe fn encode_utf16_fallback(mut src: *const u8, len: usize, mut dst: *mut u16, case: AsciiCase) {
+    let charset = match case {
+        AsciiCase::Lower => vsimd::hex::LOWER_CHARSET,
+        AsciiCase::Upper => vsimd::hex::UPPER_CHARSET,
+    };
+
+    let end = src.add(len);
+    while src < end {
+        let x = src.read();
+        dst.write(u16::from(charset[(x >> 4) as usize]));
+        dst.add(1).write(u16::from(charset[(x & 0x0f) as usize]));
+        src = src.add(1);
+        dst = dst.add(2);
+    }
+}
+
+#[inline(always)]
+pub unsafe fn encode_utf16_simd<S: SIMD256>(s: S, mut src: *const u8, len: usize, mut dst: *mut u16, case: AsciiCase) {
+    let end = src.add(len / 16 * 16);
+
+    if is_isa_type!(S, SSE2) {
+        let s = SSE2::new();
+        let offset = match case {
+            AsciiCase::Lower => vsimd::hex::sse2::LOWER_OFFSET,
+            AsciiCase::Upper => vsimd::hex::sse2::UPPER_OFFSET,
+        };
+        while src < end {
+            let x = s.v128_load_unaligned(src);
+            let (y1, y2) = vsimd::hex::sse2::encode16(s, x, offset);
+            s.v256_store_unaligned(dst.cast(), s.u16x16_from_u8x16(y1));
+            s.v256_store_unaligned(dst.add(16).cast(), s.u16x16_from_u8x16(y2));
+            src = src.add(16);
+            dst = dst.add(32);
+        }
+    } else {
+        let lut = match case {
+            AsciiCase::Lower => vsimd::hex::ENCODE_LOWER_LUT,
+            AsciiCase::Upper => vsimd::hex::ENCODE_UPPER_LUT,
+        };
+        while src < end {
+            let x = s.v128_load_unaligned(src);
+            let (y1, y2) = vsimd::hex::encode_bytes16(s, x, lut).to_v128x2();
+            s.v256_store_unaligned(dst.cast(), s.u16x16_from_u8x16(y1));
+            s.v256_store_unaligned(dst.add(16).cast(), s.u16x16_from_u8x16(y2));
+            src = src.add(16);
+            dst = dst.add(32);
+        }
+    }
+
+    encode_utf16_fallback(src, len % 16, dst, case);
+}
+
+#[inline(always)]
+fn unhex_utf16(x: u16) -> u8 {
+    match u8::try_from(x) {
+        Ok(x) => unhex(x),
+        Err(_) => 0xff,
+    }
+}
+
+#[inline(always)]
+pub unsafe fn decode_utf16_fallback(mut src: *const u16, len: usize, mut dst: *mut u8) -> Result<(), Error> {
+    let end = src.add(len);
+    let mut flag = 0;
+    while src < end {
+        let y1 = unhex_utf16(src.read());
+        let y2 = unhex_utf16(src.add(1).read());
+        flag |= y1 | y2;
+        dst.write(y1.wrapping_shl(4) | y2);
+        src = src.add(2);
+        dst = dst.add(1);
+    }
+    ensure!(flag != 0xff);
+    Ok(())
+}
+
+#[inline(always)]
+pub unsafe fn decode_utf16_simd<S: SIMD256>(
+    s: S,
+    mut src: *const u16,
+    len: usize,
+    mut dst: *mut u8,
+) -> Result<(), Error> {
+    if is_isa_type!(S, SSE2) {
+        let s = SSE2::new();
+        let end = src.add(len / 16 * 16);
+        while src < end {
+            // code units above 0xff saturate to 0x00 or 0xff, which are rejected as hex
+            let x1 = s.v128_load_unaligned(src.cast());
+            let x2 = s.v128_load_unaligned(src.add(8).cast());
+            let x = s.i16x8_packus(x1, x2);
+
+            let (nibbles, flag) = vsimd::hex::sse2::decode_nibbles(s, x);
+            ensure!(s.u8x16_bitmask(flag) == 0);
+
+            let ans = vsimd::hex::sse2::merge_bits(s, nibbles);
+            dst.cast::<V64>().write_unaligned(ans);
+
+            src = src.add(16);
+            dst = dst.add(8);
+        }
+        return decode_utf16_fallback(src, len % 16, dst);
+    }
+
+    let end = src.add(len / 32 * 32);
+    while src < end {
+        let x1 = s.v256_load_unaligned(src.cast());
+        let x2 = s.v256_load_unaligned(src.add(16).cast());
+
+        // any non-zero high byte becomes a high bit
+        let high = s.u16x16_shr::<8>(s.v256_or(x1, x2));
+        ensure!(!u8x32_highbit_any(s, s.u8x32_add_sat(high, s.u8x32_splat(0x7f))));
+
+        let x = vsimd::hex::unzip_even_bytes(s, x1, x2);
+        let y = try_!(vsimd::hex::decode_ascii32(s, x));
+        s.v128_store_unaligned(dst, y);
+
+        src = src.add(32);
+        dst = dst.add(16);
+    }
+    decode_utf16_fallback(src, len % 32, dst)
+}
@@ -155,3 +155,476 @@ fn random() {
         test_encode_decode_inplace!(src, AsciiCase::Upper);
     }
 }
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn dump() {
+    use hex_simd::dump::{CANONICAL, XXD};
+
+    let src = b"Hello world!\nabcdefghijklmnopqrstu\x00\x01\xff";
+
+    let cases = [
+        (
+            XXD,
+            concat!(
+                "00000000: 4865 6c6c 6f20 776f 726c 6421 0a61 6263  Hello world!.abc\n",
+                "00000010: 6465 6667 6869 6a6b 6c6d 6e6f 7071 7273  defghijklmnopqrs\n",
+                "00000020: 7475 0001 ff                             tu...\n",
+            ),
+        ),
+        (
+            // `xxd -u -g4 -c10 -o 0xfffffffa` of xxd 2022-01-14
+            XXD.group(4).columns(10).case(AsciiCase::Upper).offset(0xffff_fffa),
+            concat!(
+                "fffffffa: 48656C6C 6F20776F 726C  Hello worl\n",
+                "100000004: 64210A61 62636465 6667  d!.abcdefg\n",
+                "10000000e: 68696A6B 6C6D6E6F 7071  hijklmnopq\n",
+                "100000018: 72737475 0001FF         rstu...\n",
+            ),
+        ),
+        (
+            CANONICAL,
+            concat!(
+                "00000000  48 65 6c 6c 6f 20 77 6f  72 6c 64 21 0a 61 62 63  |Hello world!.abc|\n",
+                "00000010  64 65 66 67 68 69 6a 6b  6c 6d 6e 6f 70 71 72 73  |defghijklmnopqrs|\n",
+                "00000020  74 75 00 01 ff                                    |tu...|\n",
+                "00000025\n",
+            ),
+        ),
+    ];
+
+    for (dump, expected) in cases {
+        assert_eq!(dump.dumped_length(src.len()), expected.len());
+
+        let ans = dump.dump_to_string(src);
+        assert_eq!(ans, expected);
+
+        let mut buf = vec![0; expected.len()];
+        let ans = dump.dump_as_str(src, buf.as_out()).unwrap();
+        assert_eq!(ans, expected);
+
+        let mut buf = vec![0; src.len()];
+        let ans = dump.parse(expected.as_bytes(), buf.as_out()).unwrap();
+        assert_eq!(ans, src);
+    }
+
+    // several blocks, which are encoded separately
+    for n in [2047, 2048, 2049, 5000] {
+        let bytes = rand_bytes(n);
+        for columns in [7, 16, 256] {
+            let dump = XXD.columns(columns);
+            let text = dump.dump_to_string(&bytes);
+            let lines: String = (bytes.chunks(columns).enumerate())
+                .map(|(i, line)| dump.offset((i * columns) as u64).dump_to_string(line))
+                .collect();
+            assert_eq!(text, lines);
+            assert_eq!(dump.parse_to_vec(&text).unwrap(), bytes);
+            let dump = CANONICAL.columns(columns);
+            assert_eq!(dump.parse_to_vec(dump.dump_to_string(&bytes)).unwrap(), bytes);
+        }
+    }
+
+    // short lines without padding, as accepted by `xxd -r`
+    let short_cases = [
+        (XXD, "00000000: 6162 63  abc\n"),
+        (XXD, "00000000: 6162 63  abc\n00000003: 64  d\n"),
+        (XXD, "00000000: 6162 63    ab 12\n00000003: 64  d"),
+        (CANONICAL, "00000000  61 62 63  |abc|\n00000003\n"),
+        (CANONICAL, "00000000  61 62 63 |a 12|\n00000003  64 |d|\n"),
+    ];
+    for (dump, text) in short_cases {
+        let expected: &[u8] = if text.contains("64") { b"abcd" } else { b"abc" };
+        assert_eq!(dump.parse_to_vec(text).unwrap(), expected, "text = {text:?}");
+    }
+
+    assert_eq!(XXD.dump_to_string(b""), "");
+    assert_eq!(CANONICAL.dump_to_string(b""), "");
+
+    let err_cases = [
+        "00000000: 4865 6c6c 6f20 776f 726c 6421 0a         Hello world!.\n00000000: 00",
+        "00000000: 4865 6c6c 6f20 776f 726c 6421 0x         Hello world!.\n",
+        "00000000: 486                                      H\n",
+        "00000000 4865\n",
+    ];
+    for src in err_cases {
+        assert!(XXD.parse_to_vec(src).is_err(), "src = {src:?}");
+    }
+
+    for n in 0..128 {
+        let bytes = rand_bytes(n);
+        for dump in [XXD, XXD.columns(7).group(3), CANONICAL, CANONICAL.columns(32).group(4)] {
+            let text = dump.dump_to_string(&bytes);
+            assert_eq!(text.len(), dump.dumped_length(n));
+            assert_eq!(dump.parse_to_vec(&text).unwrap(), bytes);
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn forgiving() {
+    let ok_cases: &[(&str, &[u8])] = &[
+        ("", &[]),
+        ("0x", &[]),
+        ("0xDEADBEEF", &[0xde, 0xad, 0xbe, 0xef]),
+        ("0Xdeadbeef", &[0xde, 0xad, 0xbe, 0xef]),
+        ("de ad be ef", &[0xde, 0xad, 0xbe, 0xef]),
+        ("  0xde\tad\r\nbe\x0cef\n", &[0xde, 0xad, 0xbe, 0xef]),
+        ("d e a d", &[0xde, 0xad]),
+    ];
+
+    let err_cases: &[&str] = &["0", "0x0", "x0", "de:ad", "0x 0x00", "de ad b", "\0\0"];
+
+    for &(src, expected) in ok_cases {
+        let mut buf = src.as_bytes().to_owned();
+        let ans = hex_simd::forgiving_decode_inplace(&mut buf).unwrap();
+        assert_eq!(ans, expected, "src = {src:?}");
+
+        let mut buf = vec![0; expected.len()];
+        let ans = hex_simd::forgiving_decode(src.as_bytes(), buf.as_out()).unwrap();
+        assert_eq!(ans, expected, "src = {src:?}");
+
+        #[cfg(feature = "alloc")]
+        {
+            let ans = hex_simd::forgiving_decode_to_vec(src).unwrap();
+            assert_eq!(ans, expected, "src = {src:?}");
+        }
+    }
+
+    for &src in err_cases {
+        let mut buf = src.as_bytes().to_owned();
+        assert!(hex_simd::forgiving_decode_inplace(&mut buf).is_err(), "src = {src:?}");
+
+        let mut buf = vec![0; src.len()];
+        assert!(
+            hex_simd::forgiving_decode(src.as_bytes(), buf.as_out()).is_err(),
+            "src = {src:?}"
+        );
+    }
+
+    let separators = [" ", "\n", "\r\n", "\t ", ""];
+    for n in 0..300 {
+        let bytes = rand_bytes(n);
+        for (i, sep) in separators.iter().enumerate() {
+            let mut text = String::from(if i % 2 == 0 { "0x" } else { "" });
+            for (j, b) in bytes.iter().enumerate() {
+                if j % (i + 1) == 0 {
+                    text.push_str(sep);
+                }
+                text.push_str(&format!("{b:02X}"));
+            }
+
+            let mut buf = text.clone().into_bytes();
+            assert_eq!(hex_simd::forgiving_decode_inplace(&mut buf).unwrap(), bytes);
+
+            let mut buf = vec![0; n];
+            assert_eq!(
+                hex_simd::forgiving_decode(text.as_bytes(), buf.as_out()).unwrap(),
+                bytes
+            );
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn case_strict() {
+    use AsciiCase::{Lower, Upper};
+
+    assert!(hex_simd::check_case(b"0123456789abcdef", Lower).is_ok());
+    assert!(hex_simd::check_case(b"0123456789ABCDEF", Upper).is_ok());
+    assert!(hex_simd::check_case(b"0123456789abcdeF", Lower).is_err());
+    assert!(hex_simd::check_case(b"0123456789ABCDEf", Upper).is_err());
+    assert!(hex_simd::check_case(b"0123456789abcdeg", Lower).is_err());
+
+    for n in 0..150 {
+        let bytes = rand_bytes(n);
+        for (case, other) in [(Lower, b'A'), (Upper, b'a')] {
+            let mut encoded = vec![0; n * 2];
+            let encoded = hex_simd::encode(&bytes, encoded.as_out(), case).unwrap();
+            assert!(hex_simd::check_case(encoded, case).is_ok());
+
+            let mut buf = vec![0; n];
+            let ans = hex_simd::decode_case(encoded, buf.as_out(), case).unwrap();
+            assert_eq!(ans, bytes);
+
+            for i in 0..encoded.len() {
+                let mut src = encoded.to_owned();
+                src[i] = other;
+                assert!(hex_simd::check_case(&src, case).is_err(), "n = {n}, i = {i}");
+                assert!(hex_simd::check(&src).is_ok());
+
+                let mut buf = vec![0; n];
+                assert!(hex_simd::decode_case(&src, buf.as_out(), case).is_err());
+
+                src[i] = b'g';
+                assert!(hex_simd::check_case(&src, case).is_err());
+                assert!(hex_simd::decode_case(&src, buf.as_out(), case).is_err());
+            }
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn integer() {
+    use AsciiCase::{Lower, Upper};
+
+    assert_eq!(&hex_simd::format_u32(0xdead_beef, Upper), b"DEADBEEF");
+    assert_eq!(&hex_simd::format_u64(0x1f, Lower), b"000000000000001f");
+    assert_eq!(
+        &hex_simd::format_u128(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210, Lower),
+        b"0123456789abcdeffedcba9876543210"
+    );
+
+    assert_eq!(hex_simd::parse_u32(b"DEADbeef").unwrap(), 0xdead_beef);
+    assert_eq!(hex_simd::parse_u64(b"000000000000001f").unwrap(), 0x1f);
+    assert_eq!(hex_simd::parse_u128(&[b'f'; 32]).unwrap(), u128::MAX);
+    assert!(hex_simd::parse_u32(b"0000000g").is_err());
+    assert!(hex_simd::parse_u64(b"x000000000000000").is_err());
+    assert!(hex_simd::parse_u128(b"0000000000000000000000000000000-").is_err());
+
+    for _ in 0..1000 {
+        let x = u128::from_be_bytes(rand_bytes(16).try_into().unwrap());
+        for case in [Lower, Upper] {
+            assert_eq!(
+                hex_simd::parse_u32(&hex_simd::format_u32(x as u32, case)).unwrap(),
+                x as u32
+            );
+            assert_eq!(
+                hex_simd::parse_u64(&hex_simd::format_u64(x as u64, case)).unwrap(),
+                x as u64
+            );
+            assert_eq!(hex_simd::parse_u128(&hex_simd::format_u128(x, case)).unwrap(), x);
+
+            let expected = format!("{x:032x}");
+            assert_eq!(hex_simd::format_u128(x, Lower), expected.as_bytes());
+            let expected = format!("{:016X}", x as u64);
+            assert_eq!(hex_simd::format_u64(x as u64, Upper), expected.as_bytes());
+            let expected = format!("{:08x}", x as u32);
+            assert_eq!(hex_simd::format_u32(x as u32, Lower), expected.as_bytes());
+        }
+    }
+
+    for i in 0..8 {
+        let mut buf = *b"01234567";
+        buf[i] = b'g';
+        assert!(hex_simd::parse_u32(&buf).is_err());
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn otel_ids() {
+    // https://www.w3.org/TR/trace-context/#examples-of-http-traceparent-headers
+    let trace_id = *b"4bf92f3577b34da6a3ce929d0e0e4736";
+    let span_id = *b"00f067aa0ba902b7";
+
+    let id = hex_simd::parse_trace_id(&trace_id).unwrap();
+    assert_eq!(id, 0x4bf9_2f35_77b3_4da6_a3ce_929d_0e0e_4736_u128.to_be_bytes());
+    assert_eq!(hex_simd::format_trace_id(&id), trace_id);
+
+    let id = hex_simd::parse_span_id(&span_id).unwrap();
+    assert_eq!(id, 0x00f0_67aa_0ba9_02b7_u64.to_be_bytes());
+    assert_eq!(hex_simd::format_span_id(&id), span_id);
+
+    assert!(hex_simd::parse_trace_id(b"4BF92F3577B34DA6A3CE929D0E0E4736").is_err());
+    assert!(hex_simd::parse_trace_id(&[b'0'; 32]).is_err());
+    assert!(hex_simd::parse_span_id(b"00F067AA0BA902B7").is_err());
+    assert!(hex_simd::parse_span_id(&[b'0'; 16]).is_err());
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn reversed() {
+    let mut buf = [0; 8];
+    let ans = hex_simd::encode_reversed(&[0x01, 0x23, 0xab, 0xcd], buf.as_mut_slice().as_out(), AsciiCase::Upper);
+    assert_eq!(ans.unwrap(), b"CDAB2301");
+
+    let mut buf = [0; 4];
+    let ans = hex_simd::decode_reversed(b"cdAB2301", buf.as_mut_slice().as_out());
+    assert_eq!(ans.unwrap(), [0x01, 0x23, 0xab, 0xcd]);
+
+    let mut buf = [0; 4];
+    assert!(hex_simd::decode_reversed(b"cdAB230", buf.as_mut_slice().as_out()).is_err());
+    assert!(hex_simd::decode_reversed(b"cdAB23g1", buf.as_mut_slice().as_out()).is_err());
+
+    for n in 0..200 {
+        let bytes = rand_bytes(n);
+        let mut reversed = bytes.clone();
+        reversed.reverse();
+
+        for case in [AsciiCase::Lower, AsciiCase::Upper] {
+            let mut expected = vec![0; n * 2];
+            let expected = hex_simd::encode(&reversed, expected.as_out(), case).unwrap();
+
+            let mut encoded = vec![0; n * 2];
+            let encoded = hex_simd::encode_reversed(&bytes, encoded.as_out(), case).unwrap();
+            assert_eq!(encoded, expected);
+
+            let mut decoded = vec![0; n];
+            let decoded = hex_simd::decode_reversed(encoded, decoded.as_out()).unwrap();
+            assert_eq!(decoded, bytes);
+
+            for i in 0..encoded.len() {
+                let mut src = encoded.to_owned();
+                src[i] = b'x';
+                let mut buf = vec![0; n];
+                assert!(hex_simd::decode_reversed(&src, buf.as_out()).is_err());
+            }
+        }
+    }
+}
+
+// RUSTFLAGS=-Zsanitizer=address cargo test -p hex-simd --features=parallel -- --include-ignored parallel
+#[cfg(all(not(miri), feature = "parallel"))]
+#[test]
+#[ignore = "slow"]
+fn parallel() {
+    let mut buf1 = vec![0; 200_000];
+    let mut buf2 = vec![0; 200_000];
+    let mut buf3 = vec![0; 100_000];
+    for n in (0..100_000).step_by(997) {
+        let src = rand_bytes(n);
+        for case in [AsciiCase::Lower, AsciiCase::Upper] {
+            let ans1 = hex_simd::par_encode(&src, buf1.as_out(), case).unwrap();
+            let ans2 = hex_simd::encode(&src, buf2.as_out(), case).unwrap();
+            assert!(ans1 == ans2, "n = {n}");
+
+            assert!(hex_simd::par_check(ans1).is_ok());
+            let ans3 = hex_simd::par_decode(ans1, buf3.as_out()).unwrap();
+            assert!(ans3 == src, "n = {n}");
+
+            if n > 0 {
+                ans1[n] = b'z';
+                assert!(hex_simd::par_check(ans1).is_err());
+                assert!(hex_simd::par_decode(ans1, buf3.as_out()).is_err());
+            }
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn array() {
+    use hex_simd::array::Array;
+
+    let src = b"Hello world!\n\xab";
+
+    let cases = [
+        (
+            Array::new(),
+            concat!(
+                "  0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x77, 0x6f, 0x72, 0x6c, 0x64, 0x21,\n",
+                "  0x0a, 0xab\n",
+            ),
+        ),
+        (
+            Array::new().columns(5).indent(4).case(AsciiCase::Upper),
+            concat!(
+                "    0x48, 0x65, 0x6C, 0x6C, 0x6F,\n",
+                "    0x20, 0x77, 0x6F, 0x72, 0x6C,\n",
+                "    0x64, 0x21, 0x0A, 0xAB\n",
+            ),
+        ),
+        (
+            Array::new().columns(14).indent(0),
+            "0x48, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x77, 0x6f, 0x72, 0x6c, 0x64, 0x21, 0x0a, 0xab\n",
+        ),
+    ];
+
+    for (array, expected) in cases {
+        assert_eq!(array.emitted_length(src.len()), expected.len());
+
+        let mut buf = vec![0; expected.len()];
+        let ans = array.emit_as_str(src, buf.as_out()).unwrap();
+        assert_eq!(ans, expected);
+
+        let mut buf = vec![0; src.len()];
+        let ans = array.parse(expected.as_bytes(), buf.as_out()).unwrap();
+        assert_eq!(ans, src);
+
+        let mut buf = vec![0; src.len() - 1];
+        assert!(array.parse(expected.as_bytes(), buf.as_out()).is_err());
+    }
+
+    let ok_cases: &[(&str, &[u8])] = &[
+        ("", &[]),
+        (" \n", &[]),
+        ("0x1", &[0x01]),
+        ("0X1f,", &[0x1f]),
+        ("0xA ,\t0x0b ,\n 0xc,", &[0x0a, 0x0b, 0x0c]),
+    ];
+    for &(src, expected) in ok_cases {
+        let mut buf = vec![0; expected.len()];
+        let ans = Array::new().parse(src.as_bytes(), buf.as_out()).unwrap();
+        assert_eq!(ans, expected, "src = {src:?}");
+    }
+
+    let err_cases: &[&str] = &[",", "0x", "0x123", "0xgg", "0x1 0x2", "0x1,,0x2", "1f", "0x1f;"];
+    for &src in err_cases {
+        let mut buf = vec![0; src.len()];
+        assert!(
+            Array::new().parse(src.as_bytes(), buf.as_out()).is_err(),
+            "src = {src:?}"
+        );
+    }
+
+    for n in 0..600 {
+        let bytes = rand_bytes(n);
+        for array in [Array::new(), Array::new().columns(1).indent(0), Array::new().columns(7)] {
+            let mut buf = vec![0; array.emitted_length(n)];
+            let text = array.emit(&bytes, buf.as_out()).unwrap().to_owned();
+
+            let mut buf = vec![0; n];
+            assert_eq!(array.parse(&text, buf.as_out()).unwrap(), bytes);
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn utf16() {
+    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+
+    let mut buf = [0; 8];
+    let ans = hex_simd::encode_utf16(&[0x01, 0x23, 0xab, 0xcd], buf.as_mut_slice().as_out(), AsciiCase::Upper);
+    assert_eq!(ans.unwrap(), utf16("0123ABCD"));
+
+    let mut buf = [0; 4];
+    let ans = hex_simd::decode_utf16(&utf16("0123abCD"), buf.as_mut_slice().as_out());
+    assert_eq!(ans.unwrap(), [0x01, 0x23, 0xab, 0xcd]);
+
+    for n in 0..200 {
+        let bytes = rand_bytes(n);
+        for case in [AsciiCase::Lower, AsciiCase::Upper] {
+            let mut expected = vec![0; n * 2];
+            let expected = hex_simd::encode(&bytes, expected.as_out(), case).unwrap();
+            let expected: Vec<u16> = expected.iter().copied().map(u16::from).collect();
+
+            let mut encoded = vec![0; n * 2];
+            let encoded = hex_simd::encode_utf16(&bytes, encoded.as_out(), case).unwrap();
+            assert_eq!(encoded, expected);
+
+            let mut decoded = vec![0; n];
+            let decoded = hex_simd::decode_utf16(encoded, decoded.as_out()).unwrap();
+            assert_eq!(decoded, bytes);
+
+            // non-ascii code units whose low byte is a hex character
+            for bad in [
+                u16::from(b'a') | 0x0100,
+                u16::from(b'0') | 0x8000,
+                0xff30,
+                u16::from(b'g'),
+            ] {
+                for i in 0..encoded.len() {
+                    let mut src = encoded.to_owned();
+                    src[i] = bad;
+                    let mut buf = vec![0; n];
+                    assert!(hex_simd::decode_utf16(&src, buf.as_out()).is_err(), "n = {n}, i = {i}");
+                }
+            }
+        }
+    }
+}
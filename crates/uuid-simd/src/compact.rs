@@ -0,0 +1,245 @@
+use crate::{AsciiCase, Error};
+
+use vsimd::base32::{crockford_fold, EncodingLutX2};
+use vsimd::base32::{CROCKFORD_ALSW_CHECK_X2, CROCKFORD_ALSW_DECODE_X2};
+use vsimd::base64::{URL_SAFE_ALSW_CHECK_X2, URL_SAFE_ALSW_DECODE_X2};
+use vsimd::base64::{URL_SAFE_CHARSET, URL_SAFE_ENCODING_SHIFT_X2};
+use vsimd::tools::{read, write};
+use vsimd::SIMD256;
+
+const CROCKFORD_UPPER_CHARSET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const CROCKFORD_LOWER_CHARSET: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+const BASE58_CHARSET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const fn decoding_table<const N: usize>(charset: &[u8; N]) -> [u8; 256] {
+    let mut table = [0xff; 256];
+    let mut i = 0;
+    while i < N {
+        table[charset[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+const BASE64URL_TABLE: &[u8; 256] = &decoding_table(URL_SAFE_CHARSET);
+const BASE58_TABLE: &[u8; 256] = &decoding_table(BASE58_CHARSET);
+
+const CROCKFORD_TABLE: &[u8; 256] = &{
+    let mut table = decoding_table(CROCKFORD_UPPER_CHARSET);
+    let mut i = 0;
+    while i < 32 {
+        table[CROCKFORD_LOWER_CHARSET[i] as usize] = i as u8;
+        i += 1;
+    }
+    table[b'O' as usize] = 0;
+    table[b'o' as usize] = 0;
+    table[b'I' as usize] = 1;
+    table[b'i' as usize] = 1;
+    table[b'L' as usize] = 1;
+    table[b'l' as usize] = 1;
+    table
+};
+
+const CROCKFORD_UPPER_LUT: EncodingLutX2 = EncodingLutX2::new(CROCKFORD_UPPER_CHARSET);
+const CROCKFORD_LOWER_LUT: EncodingLutX2 = EncodingLutX2::new(CROCKFORD_LOWER_CHARSET);
+
+#[inline(always)]
+const fn crockford_charset(case: AsciiCase) -> &'static [u8; 32] {
+    match case {
+        AsciiCase::Lower => CROCKFORD_LOWER_CHARSET,
+        AsciiCase::Upper => CROCKFORD_UPPER_CHARSET,
+    }
+}
+
+#[inline(always)]
+unsafe fn read_u128(src: *const u8) -> u128 {
+    u128::from_be_bytes(src.cast::<[u8; 16]>().read_unaligned())
+}
+
+#[inline(always)]
+unsafe fn write_u128(dst: *mut u8, x: u128) {
+    dst.cast::<[u8; 16]>().write_unaligned(x.to_be_bytes());
+}
+
+/// Encodes the 128 bits as 21 six-bit characters and one character of the last 2 bits.
+#[inline(always)]
+pub unsafe fn format_base64url_fallback(src: *const u8, dst: *mut u8) {
+    let charset = URL_SAFE_CHARSET.as_ptr();
+    let x = read_u128(src);
+    for i in 0..21 {
+        let shift = 122 - i * 6;
+        write(dst, i, read(charset, ((x >> shift) & 0x3f) as usize));
+    }
+    write(dst, 21, read(charset, ((x & 0x03) << 4) as usize));
+}
+
+#[inline(always)]
+pub unsafe fn parse_base64url_fallback(src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    let table = BASE64URL_TABLE.as_ptr();
+    let mut x: u128 = 0;
+    let mut flag = 0;
+    for i in 0..21 {
+        let v = read(table, read(src, i) as usize);
+        flag |= v;
+        x = (x << 6) | (v & 0x3f) as u128;
+    }
+    let v = read(table, read(src, 21) as usize);
+    // the unused bits must be zero
+    ensure!(flag != 0xff && v != 0xff && v.trailing_zeros() >= 4);
+    write_u128(dst, (x << 2) | (v >> 4) as u128);
+    Ok(())
+}
+
+/// Encodes the 128 bits as 26 characters, with 2 leading zero bits like ULID.
+#[inline(always)]
+pub unsafe fn format_crockford_fallback(src: *const u8, dst: *mut u8, case: AsciiCase) {
+    let charset = crockford_charset(case).as_ptr();
+    let x = read_u128(src);
+    for i in 0..26 {
+        let shift = 125 - i * 5;
+        write(dst, i, read(charset, ((x >> shift) & 0x1f) as usize));
+    }
+}
+
+#[inline(always)]
+pub unsafe fn parse_crockford_fallback(src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    let table = CROCKFORD_TABLE.as_ptr();
+    let mut x: u128 = 0;
+    let mut flag = 0;
+    for i in 0..26 {
+        let v = read(table, read(src, i) as usize);
+        flag |= v;
+        x = (x << 5) | (v & 0x1f) as u128;
+    }
+    // the first character carries only 3 bits
+    ensure!(flag != 0xff && read(table, read(src, 0) as usize) < 8);
+    write_u128(dst, x);
+    Ok(())
+}
+
+/// Encodes the 16 bytes as the first 22 characters of a 24-byte base64 block.
+#[inline(always)]
+pub unsafe fn format_base64url_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) {
+    // x: {????|AAAB|BBCC|CDDD|EEEF|0000|0000|????}
+    let mut buf = [0u8; 32];
+    buf.as_mut_ptr().add(4).copy_from_nonoverlapping(src, 16);
+    let x = s.v256_load_unaligned(buf.as_ptr());
+
+    let y = vsimd::base64::encode_bytes24(s, x, URL_SAFE_ENCODING_SHIFT_X2);
+    s.v256_store_unaligned(buf.as_mut_ptr(), y);
+    dst.copy_from_nonoverlapping(buf.as_ptr(), 22);
+}
+
+/// Decodes the 22 characters as a 32-character base64 block padded with `A`.
+#[inline(always)]
+pub unsafe fn parse_base64url_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    let mut buf = [b'A'; 32];
+    buf.as_mut_ptr().copy_from_nonoverlapping(src, 22);
+    let x = s.v256_load_unaligned(buf.as_ptr());
+
+    let y = try_!(vsimd::base64::decode_ascii32(
+        s,
+        x,
+        URL_SAFE_ALSW_CHECK_X2,
+        URL_SAFE_ALSW_DECODE_X2
+    ));
+    // y: {AAAB|BBCC|CDDD|0000|EEEF|????|????|0000}
+    s.v256_store_unaligned(buf.as_mut_ptr(), y);
+
+    // the unused bits must be zero
+    ensure!(buf[20] == 0);
+    dst.copy_from_nonoverlapping(buf.as_ptr(), 12);
+    dst.add(12).copy_from_nonoverlapping(buf.as_ptr().add(16), 4);
+    Ok(())
+}
+
+/// Encodes the top 3 bits as the first character,
+/// and the other 125 bits as the first 25 characters of a 20-byte base32 block.
+#[inline(always)]
+pub unsafe fn format_crockford_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8, case: AsciiCase) {
+    let lut = match case {
+        AsciiCase::Lower => CROCKFORD_LOWER_LUT,
+        AsciiCase::Upper => CROCKFORD_UPPER_LUT,
+    };
+    let x = read_u128(src);
+
+    // {????|??AA|AAAB|BBBB|CCCC|CDDD|DD??|????}
+    let mut buf = [0u8; 32];
+    buf.as_mut_ptr().add(6).cast::<[u8; 16]>().write((x << 3).to_be_bytes());
+    let y = vsimd::base32::encode_bytes20(s, s.v256_load_unaligned(buf.as_ptr()), lut);
+    s.v256_store_unaligned(buf.as_mut_ptr(), y);
+
+    write(dst, 0, read(crockford_charset(case).as_ptr(), (x >> 125) as usize));
+    dst.add(1).copy_from_nonoverlapping(buf.as_ptr(), 25);
+}
+
+/// Decodes the last 25 characters as a 32-character base32 block padded with `0`.
+#[inline(always)]
+pub unsafe fn parse_crockford_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    // the first character carries only 3 bits
+    let first = read(CROCKFORD_TABLE.as_ptr(), read(src, 0) as usize);
+    ensure!(first < 8);
+
+    let mut buf = [b'0'; 32];
+    buf.as_mut_ptr().copy_from_nonoverlapping(src.add(1), 25);
+    let x = crockford_fold(s, s.v256_load_unaligned(buf.as_ptr()));
+
+    let y = try_!(vsimd::base32::decode_ascii32(
+        s,
+        x,
+        CROCKFORD_ALSW_CHECK_X2,
+        CROCKFORD_ALSW_DECODE_X2
+    ));
+    // y: {10 bytes|000000|10 bytes|000000}
+    s.v256_store_unaligned(buf.as_mut_ptr(), y);
+
+    let mut bytes = [0u8; 16];
+    bytes[..10].copy_from_slice(&buf[..10]);
+    bytes[10..].copy_from_slice(&buf[16..22]);
+    let rest = u128::from_be_bytes(bytes) >> 3;
+
+    write_u128(dst, ((first as u128) << 125) | rest);
+    Ok(())
+}
+
+/// `58^10`, the largest power of 58 that fits in `u64`.
+const BASE58_CHUNK: u64 = 430_804_206_899_405_824;
+
+/// Encodes the 128 bits as a 22-digit base58 number, with leading `1` as zeros.
+#[inline(always)]
+pub fn format_base58(src: &[u8; 16], dst: &mut [u8; 22]) {
+    let x = u128::from_be_bytes(*src);
+    let chunk = BASE58_CHUNK as u128;
+
+    // x = (hi * 58^10 + mid) * 58^10 + lo, where hi < 58^2
+    let lo = (x % chunk) as u64;
+    let x = x / chunk;
+    let mid = (x % chunk) as u64;
+    let hi = (x / chunk) as u64;
+
+    let mut write_digits = |mut n: u64, end: usize, count: usize| {
+        for c in dst[end - count..end].iter_mut().rev() {
+            *c = BASE58_CHARSET[(n % 58) as usize];
+            n /= 58;
+        }
+    };
+    write_digits(lo, 22, 10);
+    write_digits(mid, 12, 10);
+    write_digits(hi, 2, 2);
+}
+
+#[inline(always)]
+pub fn parse_base58(src: &[u8; 22], dst: &mut [u8; 16]) -> Result<(), Error> {
+    let mut x: u128 = 0;
+    for &c in src {
+        let v = BASE58_TABLE[c as usize];
+        ensure!(v != 0xff);
+        // 58^22 > 2^128, so the value may overflow
+        let Some(y) = x.checked_mul(58).and_then(|x| x.checked_add(v as u128)) else {
+            return Err(Error::new());
+        };
+        x = y;
+    }
+    *dst = x.to_be_bytes();
+    Ok(())
+}
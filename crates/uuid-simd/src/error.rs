@@ -1,3 +1,5 @@
+use crate::Variant;
+
 use core::fmt;
 
 /// UUID Error
@@ -45,3 +47,37 @@ macro_rules! try_ {
         }
     };
 }
+
+/// UUID validation error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// The input is not a UUID string.
+    Format,
+    /// The variant is not the RFC 9562 variant.
+    Variant(Variant),
+    /// The version is not allowed.
+    Version(u8),
+}
+
+impl From<Error> for ValidationError {
+    #[inline]
+    fn from(_: Error) -> Self {
+        ValidationError::Format
+    }
+}
+
+impl fmt::Display for ValidationError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::Format => write!(f, "UUIDError: invalid format"),
+            ValidationError::Variant(v) => write!(f, "UUIDError: unexpected variant {v:?}"),
+            ValidationError::Version(v) => write!(f, "UUIDError: unexpected version {v}"),
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
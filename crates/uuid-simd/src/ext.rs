@@ -43,6 +43,12 @@ pub trait UuidExt: Sized {
 
     /// Returns a fmt adapter with "hyphenated" format.
     fn format_hyphenated(&self) -> Hyphenated<'_>;
+
+    /// Returns a fmt adapter with "braced" format.
+    fn format_braced(&self) -> Braced<'_>;
+
+    /// Returns a fmt adapter with "urn" format.
+    fn format_urn(&self) -> Urn<'_>;
 }
 
 #[allow(clippy::type_complexity)]
@@ -82,6 +88,16 @@ impl UuidExt for Uuid {
     fn format_hyphenated(&self) -> Hyphenated<'_> {
         Hyphenated(self)
     }
+
+    #[inline]
+    fn format_braced(&self) -> Braced<'_> {
+        Braced(self)
+    }
+
+    #[inline]
+    fn format_urn(&self) -> Urn<'_> {
+        Urn(self)
+    }
 }
 
 /// A simple UUID
@@ -94,6 +110,16 @@ pub struct Simple<'a>(&'a Uuid);
 #[derive(Debug)]
 pub struct Hyphenated<'a>(&'a Uuid);
 
+/// A braced UUID
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+#[derive(Debug)]
+pub struct Braced<'a>(&'a Uuid);
+
+/// A URN prefixed UUID
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+#[derive(Debug)]
+pub struct Urn<'a>(&'a Uuid);
+
 #[allow(clippy::type_complexity)]
 #[inline]
 unsafe fn format_uuid<R, const N: usize>(
@@ -109,59 +135,37 @@ unsafe fn format_uuid<R, const N: usize>(
     g(core::str::from_utf8_unchecked(ans))
 }
 
-impl fmt::LowerHex for Simple<'_> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let case = AsciiCase::Lower;
-        unsafe { format_uuid(self.0, case, crate::format_simple, |s| <str as fmt::Display>::fmt(s, f)) }
-    }
-}
-
-impl fmt::LowerHex for Hyphenated<'_> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let case = AsciiCase::Lower;
-        unsafe {
-            format_uuid(self.0, case, crate::format_hyphenated, |s| {
-                <str as fmt::Display>::fmt(s, f)
-            })
+macro_rules! impl_fmt {
+    ($ty:ident, $f:path) => {
+        impl fmt::LowerHex for $ty<'_> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let case = AsciiCase::Lower;
+                unsafe { format_uuid(self.0, case, $f, |s| <str as fmt::Display>::fmt(s, f)) }
+            }
         }
-    }
-}
 
-impl fmt::UpperHex for Simple<'_> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let case = AsciiCase::Upper;
-        unsafe { format_uuid(self.0, case, crate::format_simple, |s| <str as fmt::Display>::fmt(s, f)) }
-    }
-}
-
-impl fmt::UpperHex for Hyphenated<'_> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let case = AsciiCase::Upper;
-        unsafe {
-            format_uuid(self.0, case, crate::format_hyphenated, |s| {
-                <str as fmt::Display>::fmt(s, f)
-            })
+        impl fmt::UpperHex for $ty<'_> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let case = AsciiCase::Upper;
+                unsafe { format_uuid(self.0, case, $f, |s| <str as fmt::Display>::fmt(s, f)) }
+            }
         }
-    }
-}
 
-impl fmt::Display for Simple<'_> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <Self as fmt::LowerHex>::fmt(self, f)
-    }
+        impl fmt::Display for $ty<'_> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                <Self as fmt::LowerHex>::fmt(self, f)
+            }
+        }
+    };
 }
 
-impl fmt::Display for Hyphenated<'_> {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        <Self as fmt::LowerHex>::fmt(self, f)
-    }
-}
+impl_fmt!(Simple, crate::format_simple);
+impl_fmt!(Hyphenated, crate::format_hyphenated);
+impl_fmt!(Braced, crate::format_braced);
+impl_fmt!(Urn, crate::format_urn);
 
 #[cfg(test)]
 mod tests {
@@ -177,8 +181,16 @@ mod tests {
 
         let a1 = u.format_simple().to_string();
         let a2 = format!("{:X}", u.format_hyphenated());
+        let a3 = u.format_braced().to_string();
+        let a4 = format!("{:X}", u.format_urn());
 
         assert_eq!(a1, s1);
         assert_eq!(a2, s2.to_ascii_uppercase());
+        assert_eq!(a3, format!("{{{s2}}}"));
+        assert_eq!(a4, format!("urn:uuid:{}", s2.to_ascii_uppercase()));
+
+        for s in [a1, a2, a3, a4] {
+            assert_eq!(Uuid::parse(s).unwrap(), u);
+        }
     }
 }
@@ -0,0 +1,145 @@
+use crate::error::ValidationError;
+
+/// The variant of a UUID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// Reserved, NCS backward compatibility (`0xxx`).
+    Ncs,
+    /// The variant specified in RFC 9562 (`10xx`).
+    Rfc9562,
+    /// Reserved, Microsoft Corporation backward compatibility (`110x`).
+    Microsoft,
+    /// Reserved for future definition (`111x`).
+    Future,
+}
+
+/// The timestamp embedded in a UUID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Timestamp {
+    /// The count of 100-nanosecond intervals since 1582-10-15 00:00:00 UTC (v1 and v6).
+    Gregorian(u64),
+    /// The count of milliseconds since the Unix epoch (v7).
+    UnixMillis(u64),
+}
+
+/// The count of 100-nanosecond intervals between 1582-10-15 and 1970-01-01.
+const GREGORIAN_UNIX_OFFSET: u64 = 0x01B2_1DD2_1381_4000;
+
+impl Timestamp {
+    /// Converts the timestamp to milliseconds since the Unix epoch.
+    ///
+    /// Returns `None` if the timestamp is earlier than the Unix epoch.
+    #[inline]
+    #[must_use]
+    pub const fn to_unix_millis(self) -> Option<u64> {
+        match self {
+            Timestamp::Gregorian(ticks) => match ticks.checked_sub(GREGORIAN_UNIX_OFFSET) {
+                Some(t) => Some(t / 10_000),
+                None => None,
+            },
+            Timestamp::UnixMillis(ms) => Some(ms),
+        }
+    }
+}
+
+/// A set of allowed UUID versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VersionSet(u16);
+
+impl VersionSet {
+    /// All versions.
+    pub const ALL: Self = Self(u16::MAX);
+
+    /// Creates a set of `versions`.
+    ///
+    /// # Panics
+    /// This function panics if any version is greater than 15.
+    #[inline]
+    #[must_use]
+    pub const fn new(versions: &[u8]) -> Self {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < versions.len() {
+            assert!(versions[i] < 16, "UUID versions are 4 bits");
+            bits |= 1 << versions[i];
+            i += 1;
+        }
+        Self(bits)
+    }
+
+    /// Returns `true` if the set contains `version`.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, version: u8) -> bool {
+        version < 16 && self.0 & (1 << version) != 0
+    }
+}
+
+/// Returns the version of a UUID.
+#[inline]
+#[must_use]
+pub const fn version(uuid: &[u8; 16]) -> u8 {
+    uuid[6] >> 4
+}
+
+/// Returns the variant of a UUID.
+#[inline]
+#[must_use]
+pub const fn variant(uuid: &[u8; 16]) -> Variant {
+    match uuid[8] >> 5 {
+        0..=3 => Variant::Ncs,
+        4 | 5 => Variant::Rfc9562,
+        6 => Variant::Microsoft,
+        _ => Variant::Future,
+    }
+}
+
+#[inline(always)]
+const fn be_u64(uuid: &[u8; 16], start: usize, end: usize) -> u64 {
+    let mut ans = 0;
+    let mut i = start;
+    while i < end {
+        ans = (ans << 8) | uuid[i] as u64;
+        i += 1;
+    }
+    ans
+}
+
+/// Returns the timestamp embedded in a v1, v6 or v7 UUID of the RFC 9562 variant.
+///
+/// Returns `None` for other UUIDs.
+#[inline]
+#[must_use]
+pub const fn timestamp(uuid: &[u8; 16]) -> Option<Timestamp> {
+    if !matches!(variant(uuid), Variant::Rfc9562) {
+        return None;
+    }
+    let time_hi = be_u64(uuid, 6, 8) & 0x0fff;
+    match version(uuid) {
+        1 => {
+            let time_low = be_u64(uuid, 0, 4);
+            let time_mid = be_u64(uuid, 4, 6);
+            Some(Timestamp::Gregorian((time_hi << 48) | (time_mid << 32) | time_low))
+        }
+        6 => Some(Timestamp::Gregorian((be_u64(uuid, 0, 6) << 12) | time_hi)),
+        7 => Some(Timestamp::UnixMillis(be_u64(uuid, 0, 6))),
+        _ => None,
+    }
+}
+
+/// Checks that a UUID is of the RFC 9562 variant and its version is in `versions`.
+///
+/// # Errors
+/// This function returns `Err` if the variant or the version is not allowed.
+#[inline]
+pub const fn validate(uuid: &[u8; 16], versions: VersionSet) -> Result<(), ValidationError> {
+    let v = variant(uuid);
+    if !matches!(v, Variant::Rfc9562) {
+        return Err(ValidationError::Variant(v));
+    }
+    let v = version(uuid);
+    if !versions.contains(v) {
+        return Err(ValidationError::Version(v));
+    }
+    Ok(())
+}
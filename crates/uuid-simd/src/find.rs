@@ -0,0 +1,38 @@
+use core::iter::FusedIterator;
+
+/// An iterator over the hyphenated UUIDs in a haystack, created by [`find_iter`](crate::find_iter).
+#[derive(Debug, Clone)]
+pub struct FindIter<'a> {
+    haystack: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> FindIter<'a> {
+    #[inline(always)]
+    pub(crate) fn new(haystack: &'a [u8]) -> Self {
+        Self { haystack, pos: 0 }
+    }
+}
+
+impl Iterator for FindIter<'_> {
+    type Item = (usize, [u8; 16]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.haystack.get(self.pos..)?;
+        let mut uuid = [0u8; 16];
+        let k = unsafe {
+            let dst = uuid.as_mut_ptr();
+            crate::multiversion::find_hyphenated::auto(rest.as_ptr(), rest.len(), dst)
+        };
+        if k == usize::MAX {
+            self.pos = self.haystack.len() + 1;
+            return None;
+        }
+        let offset = self.pos + k;
+        self.pos = offset + 36;
+        Some((offset, uuid))
+    }
+}
+
+impl FusedIterator for FindIter<'_> {}
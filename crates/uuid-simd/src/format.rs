@@ -57,6 +57,33 @@ pub unsafe fn format_hyphenated_fallback(src: *const u8, dst: *mut u8, case: Asc
     }
 }
 
+#[inline(always)]
+pub unsafe fn format_braced_fallback(src: *const u8, dst: *mut u8, case: AsciiCase) {
+    write(dst, 0, b'{');
+    format_hyphenated_fallback(src, dst.add(1), case);
+    write(dst, 37, b'}');
+}
+
+#[inline(always)]
+pub unsafe fn format_urn_fallback(src: *const u8, dst: *mut u8, case: AsciiCase) {
+    dst.cast::<[u8; 9]>().write_unaligned(*URN_PREFIX);
+    format_hyphenated_fallback(src, dst.add(9), case);
+}
+
+#[inline(always)]
+pub unsafe fn format_hyphenated_batch_fallback(src: *const u8, n: usize, dst: *mut u8, case: AsciiCase) {
+    for i in 0..n {
+        format_hyphenated_fallback(src.add(i * 16), dst.add(i * 36), case);
+    }
+}
+
+#[inline(always)]
+pub unsafe fn format_hyphenated_le_fallback(src: *const u8, dst: *mut u8, case: AsciiCase) {
+    let mut buf = [0u8; 16];
+    guid_le_swap(src, buf.as_mut_ptr());
+    format_hyphenated_fallback(buf.as_ptr(), dst, case);
+}
+
 #[inline(always)]
 const fn char_lut_simd(case: AsciiCase) -> V256 {
     match case {
@@ -80,6 +107,28 @@ pub unsafe fn format_simple_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8,
 
 #[inline(always)]
 pub unsafe fn format_hyphenated_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8, case: AsciiCase) {
+    format_hyphenated_simd_impl::<S, false>(s, src, dst, case);
+}
+
+#[inline(always)]
+pub unsafe fn format_hyphenated_le_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8, case: AsciiCase) {
+    format_hyphenated_simd_impl::<S, true>(s, src, dst, case);
+}
+
+#[inline(always)]
+unsafe fn format_hyphenated_simd_impl<S: SIMD256, const LE: bool>(s: S, src: *const u8, dst: *mut u8, case: AsciiCase) {
+    let lut = char_lut_simd(case);
+    let mut x = s.v128_load_unaligned(src);
+    if LE {
+        x = s.u8x16_swizzle(x, GUID_LE_SWIZZLE);
+    }
+    let a = vsimd::hex::encode_bytes16(s, x, lut);
+    store_hyphenated(s, a, dst);
+}
+
+/// Inserts the hyphens into 32 hex characters and writes the 36-byte string.
+#[inline(always)]
+unsafe fn store_hyphenated<S: SIMD256>(s: S, a: V256, dst: *mut u8) {
     const SWIZZLE: V256 = V256::from_bytes([
         0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, //
         0x80, 0x08, 0x09, 0x0a, 0x0b, 0x80, 0x0c, 0x0d, //
@@ -94,9 +143,6 @@ pub unsafe fn format_hyphenated_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
     ]);
 
-    let lut = char_lut_simd(case);
-    let a = vsimd::hex::encode_bytes16(s, s.v128_load_unaligned(src), lut);
-
     let a1 = s.u8x16x2_swizzle(a, SWIZZLE);
     let a2 = s.v256_or(a1, DASH);
     s.v256_store_unaligned(dst, a2);
@@ -108,6 +154,37 @@ pub unsafe fn format_hyphenated_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut
     core::ptr::write_unaligned(dst.add(32).cast(), bytes_28_31);
 }
 
+#[inline(always)]
+pub unsafe fn format_braced_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8, case: AsciiCase) {
+    write(dst, 0, b'{');
+    format_hyphenated_simd(s, src, dst.add(1), case);
+    write(dst, 37, b'}');
+}
+
+#[inline(always)]
+pub unsafe fn format_urn_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8, case: AsciiCase) {
+    dst.cast::<[u8; 9]>().write_unaligned(*URN_PREFIX);
+    format_hyphenated_simd(s, src, dst.add(9), case);
+}
+
+#[inline(always)]
+pub unsafe fn format_hyphenated_batch_simd<S: SIMD256>(s: S, src: *const u8, n: usize, dst: *mut u8, case: AsciiCase) {
+    let lut = char_lut_simd(case);
+
+    // two UUIDs per iteration
+    let mut i = 0;
+    while i + 2 <= n {
+        let x = s.v256_load_unaligned(src.add(i * 16));
+        let (hex1, hex2) = vsimd::hex::encode_bytes32(s, x, lut);
+        store_hyphenated(s, hex1, dst.add(i * 36));
+        store_hyphenated(s, hex2, dst.add(i * 36 + 36));
+        i += 2;
+    }
+    if i < n {
+        format_hyphenated_simd(s, src.add(i * 16), dst.add(i * 36), case);
+    }
+}
+
 #[inline(always)]
 pub unsafe fn format_simple_simd_sse2(s: SSE2, src: *const u8, dst: *mut u8, case: AsciiCase) {
     let offset = match case {
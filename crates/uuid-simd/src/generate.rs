@@ -0,0 +1,128 @@
+use crate::{format_hyphenated, AsciiCase, Out};
+
+/// A source of random bytes for UUID generation.
+pub trait RandomSource {
+    /// Fills `dst` with random bytes.
+    fn fill_bytes(&mut self, dst: &mut [u8]);
+}
+
+impl<R: RandomSource + ?Sized> RandomSource for &mut R {
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        (**self).fill_bytes(dst);
+    }
+}
+
+#[inline(always)]
+fn set_version_variant(uuid: &mut [u8; 16], version: u8) {
+    uuid[6] = (uuid[6] & 0x0f) | (version << 4);
+    uuid[8] = (uuid[8] & 0x3f) | 0x80;
+}
+
+/// Generates a v4 UUID.
+#[inline]
+#[must_use]
+pub fn new_v4<R: RandomSource + ?Sized>(rng: &mut R) -> [u8; 16] {
+    let mut uuid = [0; 16];
+    rng.fill_bytes(&mut uuid);
+    set_version_variant(&mut uuid, 4);
+    uuid
+}
+
+/// Generates a v4 UUID and formats it to a hyphenated UUID string.
+#[inline]
+#[must_use]
+pub fn format_v4<'d, R: RandomSource + ?Sized>(
+    rng: &mut R,
+    dst: Out<'d, [u8; 36]>,
+    case: AsciiCase,
+) -> &'d mut [u8; 36] {
+    format_hyphenated(&new_v4(rng), dst, case)
+}
+
+const MAX_COUNTER: u16 = 0x0fff;
+
+/// A v7 UUID generator.
+///
+/// The 12-bit `rand_a` field is a counter (RFC 9562, Section 6.2, Method 1),
+/// so the UUIDs from one generator are strictly increasing.
+///
+/// The counter starts from a random value below `0x800` in each millisecond.
+/// If it overflows, or the clock goes backwards, the timestamp of the previous UUID is reused and advanced.
+#[derive(Debug, Default, Clone)]
+pub struct V7Generator {
+    /// The timestamp of the previous UUID, or `None` before the first one.
+    last_ms: Option<u64>,
+    counter: u16,
+}
+
+impl V7Generator {
+    /// Creates a v7 UUID generator.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            last_ms: None,
+            counter: 0,
+        }
+    }
+
+    /// Generates a v7 UUID with `unix_ms`, the count of milliseconds since the Unix epoch.
+    ///
+    /// Only the lower 48 bits of the timestamp are used.
+    #[inline]
+    #[must_use]
+    pub fn generate<R: RandomSource + ?Sized>(&mut self, unix_ms: u64, rng: &mut R) -> [u8; 16] {
+        let mut uuid = [0; 16];
+        rng.fill_bytes(&mut uuid[6..]);
+
+        let ms = match self.last_ms {
+            Some(last_ms) if unix_ms <= last_ms => {
+                if self.counter < MAX_COUNTER {
+                    self.counter += 1;
+                    last_ms
+                } else {
+                    self.counter = 0;
+                    last_ms + 1
+                }
+            }
+            _ => {
+                self.counter = u16::from_be_bytes([uuid[6], uuid[7]]) & 0x07ff;
+                unix_ms
+            }
+        };
+        self.last_ms = Some(ms);
+
+        uuid[..6].copy_from_slice(&ms.to_be_bytes()[2..]);
+        uuid[6..8].copy_from_slice(&self.counter.to_be_bytes());
+        set_version_variant(&mut uuid, 7);
+        uuid
+    }
+
+    /// Generates a v7 UUID and formats it to a hyphenated UUID string.
+    #[inline]
+    #[must_use]
+    pub fn format<'d, R: RandomSource + ?Sized>(
+        &mut self,
+        unix_ms: u64,
+        rng: &mut R,
+        dst: Out<'d, [u8; 36]>,
+        case: AsciiCase,
+    ) -> &'d mut [u8; 36] {
+        format_hyphenated(&self.generate(unix_ms, rng), dst, case)
+    }
+
+    /// Generates a v7 UUID with the current system time.
+    ///
+    /// # Panics
+    /// This function panics if the system time is earlier than the Unix epoch.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn generate_now<R: RandomSource + ?Sized>(&mut self, rng: &mut R) -> [u8; 16] {
+        let now = std::time::SystemTime::now();
+        let ms = now.duration_since(std::time::UNIX_EPOCH).unwrap().as_millis();
+        self.generate(ms as u64, rng)
+    }
+}
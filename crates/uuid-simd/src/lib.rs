@@ -48,12 +48,28 @@
 
 #[macro_use]
 mod error;
-pub use self::error::Error;
+pub use self::error::{Error, ValidationError};
 
 mod spec;
 
+mod fields;
+pub use self::fields::*;
+
+mod generate;
+pub use self::generate::*;
+
+mod find;
+pub use self::find::FindIter;
+
+mod options;
+pub use self::options::ParseOptions;
+
+pub mod ulid;
+
+mod compact;
 mod format;
 mod parse;
+mod utf16;
 
 mod multiversion;
 
@@ -68,24 +84,24 @@ pub use vsimd::ascii::AsciiCase;
 
 // -------------------------------------------------------------------------------------------------
 
-use vsimd::tools::read;
+use vsimd::tools::{read, slice_mut};
 
-/// Parses an UUID from arbitrary bytes.
-///
-/// # Errors
-/// This function returns `Err` if:
-///
-/// + The length of `src` doesn't match any UUID format variants.
-/// + The content of `src` is invalid.
-#[inline]
-pub fn parse<'d>(src: &[u8], mut dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8; 16], Error> {
+type ParseFn = unsafe fn(*const u8, *mut u8) -> Result<(), Error>;
+
+#[inline(always)]
+fn parse_any<'d>(
+    src: &[u8],
+    mut dst: Out<'d, [u8; 16]>,
+    simple: ParseFn,
+    hyphenated: ParseFn,
+) -> Result<&'d mut [u8; 16], Error> {
     let n = src.len();
 
     if n == 32 {
         unsafe {
             let src = src.as_ptr();
             let dst = dst.as_mut_ptr().cast::<u8>();
-            crate::multiversion::parse_simple::auto(src, dst)?;
+            simple(src, dst)?;
             return Ok(&mut *dst.cast());
         }
     }
@@ -104,11 +120,61 @@ pub fn parse<'d>(src: &[u8], mut dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8;
             _ => return Err(Error::new()),
         };
         let dst = dst.as_mut_ptr().cast::<u8>();
-        crate::multiversion::parse_hyphenated::auto(src, dst)?;
+        hyphenated(src, dst)?;
         Ok(&mut *dst.cast())
     }
 }
 
+/// Parses an UUID from arbitrary bytes.
+///
+/// # Errors
+/// This function returns `Err` if:
+///
+/// + The length of `src` doesn't match any UUID format variants.
+/// + The content of `src` is invalid.
+#[inline]
+pub fn parse<'d>(src: &[u8], dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8; 16], Error> {
+    let simple = crate::multiversion::parse_simple::auto;
+    let hyphenated = crate::multiversion::parse_hyphenated::auto;
+    parse_any(src, dst, simple, hyphenated)
+}
+
+/// Parses an UUID from arbitrary bytes to the Microsoft GUID layout.
+///
+/// The first three fields are stored in little-endian,
+/// which matches Windows `GUID` structs, Active Directory `objectGUID` and GPT partition GUIDs.
+///
+/// # Errors
+/// This function returns `Err` if:
+///
+/// + The length of `src` doesn't match any UUID format variants.
+/// + The content of `src` is invalid.
+#[inline]
+pub fn parse_guid_le<'d>(src: &[u8], dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8; 16], Error> {
+    let simple = crate::multiversion::parse_simple_le::auto;
+    let hyphenated = crate::multiversion::parse_hyphenated_le::auto;
+    parse_any(src, dst, simple, hyphenated)
+}
+
+/// Parses an UUID from arbitrary bytes and validates its variant and version.
+///
+/// # Errors
+/// This function returns `Err` if:
+///
+/// + The length or the content of `src` is invalid.
+/// + The variant is not the RFC 9562 variant.
+/// + The version is not in `versions`.
+#[inline]
+pub fn parse_validated<'d>(
+    src: &[u8],
+    dst: Out<'d, [u8; 16]>,
+    versions: VersionSet,
+) -> Result<&'d mut [u8; 16], ValidationError> {
+    let ans = parse(src, dst)?;
+    validate(ans, versions)?;
+    Ok(ans)
+}
+
 /// Parses a simple UUID from arbitrary bytes.
 ///
 /// # Errors
@@ -168,3 +234,319 @@ pub fn format_hyphenated<'d>(src: &[u8; 16], mut dst: Out<'d, [u8; 36]>, case: A
         &mut *dst.cast()
     }
 }
+
+/// Formats an UUID in the Microsoft GUID layout to a hyphenated UUID string.
+///
+/// The first three fields of `src` are read in little-endian.
+#[inline]
+#[must_use]
+pub fn format_guid_le<'d>(src: &[u8; 16], mut dst: Out<'d, [u8; 36]>, case: AsciiCase) -> &'d mut [u8; 36] {
+    unsafe {
+        let src = src.as_ptr();
+        let dst = dst.as_mut_ptr().cast::<u8>();
+        crate::multiversion::format_hyphenated_le::auto(src, dst, case);
+        &mut *dst.cast()
+    }
+}
+
+/// Formats an UUID to a braced UUID string (Microsoft GUID).
+#[inline]
+#[must_use]
+pub fn format_braced<'d>(src: &[u8; 16], mut dst: Out<'d, [u8; 38]>, case: AsciiCase) -> &'d mut [u8; 38] {
+    unsafe {
+        let src = src.as_ptr();
+        let dst = dst.as_mut_ptr().cast::<u8>();
+        crate::multiversion::format_braced::auto(src, dst, case);
+        &mut *dst.cast()
+    }
+}
+
+/// Formats an UUID to a URN prefixed UUID string.
+///
+/// The prefix `urn:uuid:` is always lowercase.
+#[inline]
+#[must_use]
+pub fn format_urn<'d>(src: &[u8; 16], mut dst: Out<'d, [u8; 45]>, case: AsciiCase) -> &'d mut [u8; 45] {
+    unsafe {
+        let src = src.as_ptr();
+        let dst = dst.as_mut_ptr().cast::<u8>();
+        crate::multiversion::format_urn::auto(src, dst, case);
+        &mut *dst.cast()
+    }
+}
+
+/// Clears the bitmap of `n` rows.
+#[inline(always)]
+fn clear_failures(failures: &mut [u64], n: usize) -> Result<(), Error> {
+    let words = n.div_ceil(64);
+    ensure!(failures.len() >= words);
+    failures[..words].fill(0);
+    Ok(())
+}
+
+/// Parses hyphenated UUIDs stored with a fixed stride.
+///
+/// The row `i` is `src[i * stride..i * stride + 36]`, and its result is written to `dst[i]`.
+/// The number of rows is `dst.len()`.
+///
+/// Invalid rows do not abort the batch.
+/// The bit `i % 64` of `failures[i / 64]` is set if the row `i` is invalid, and `dst[i]` is zeroed.
+///
+/// Returns the number of invalid rows.
+///
+/// # Errors
+/// This function returns `Err` if:
+///
+/// + `stride < 36`
+/// + `src` is too short for `dst.len()` rows.
+/// + `failures` is shorter than `dst.len().div_ceil(64)`.
+#[inline]
+pub fn parse_hyphenated_batch(
+    src: &[u8],
+    stride: usize,
+    dst: &mut [[u8; 16]],
+    failures: &mut [u64],
+) -> Result<usize, Error> {
+    let n = dst.len();
+    ensure!(stride >= 36);
+    if n > 0 {
+        let Some(len) = (n - 1).checked_mul(stride).and_then(|x| x.checked_add(36)) else {
+            return Err(Error::new());
+        };
+        ensure!(src.len() >= len);
+    }
+    clear_failures(failures, n)?;
+
+    unsafe {
+        let src = src.as_ptr();
+        let dst = dst.as_mut_ptr().cast::<u8>();
+        let failures = failures.as_mut_ptr();
+        Ok(crate::multiversion::parse_hyphenated_batch::auto(
+            src, stride, dst, n, failures,
+        ))
+    }
+}
+
+/// Parses UUIDs of any format variants stored as variable-length rows.
+///
+/// The row `i` is `src[offsets[i]..offsets[i + 1]]`, and its result is written to `dst[i]`.
+///
+/// Invalid rows, including rows with invalid offsets, do not abort the batch.
+/// The bit `i % 64` of `failures[i / 64]` is set if the row `i` is invalid, and `dst[i]` is zeroed.
+///
+/// Returns the number of invalid rows.
+///
+/// # Errors
+/// This function returns `Err` if:
+///
+/// + `offsets.len() != dst.len() + 1`
+/// + `failures` is shorter than `dst.len().div_ceil(64)`.
+#[inline]
+pub fn parse_batch(src: &[u8], offsets: &[usize], dst: &mut [[u8; 16]], failures: &mut [u64]) -> Result<usize, Error> {
+    let n = dst.len();
+    ensure!(offsets.len() == n + 1);
+    clear_failures(failures, n)?;
+
+    unsafe {
+        let len = src.len();
+        let src = src.as_ptr();
+        let offsets = offsets.as_ptr();
+        let dst = dst.as_mut_ptr().cast::<u8>();
+        let failures = failures.as_mut_ptr();
+        Ok(crate::multiversion::parse_batch::auto(
+            src, len, offsets, dst, n, failures,
+        ))
+    }
+}
+
+/// Formats UUIDs to contiguous hyphenated UUID strings.
+///
+/// The string of `src[i]` is written to `dst[i * 36..(i + 1) * 36]`.
+///
+/// # Errors
+/// This function returns `Err` if the length of `dst` is less than `src.len() * 36`.
+#[inline]
+pub fn format_hyphenated_batch<'d>(
+    src: &[[u8; 16]],
+    mut dst: Out<'d, [u8]>,
+    case: AsciiCase,
+) -> Result<&'d mut [u8], Error> {
+    let n = src.len();
+    let Some(m) = n.checked_mul(36) else {
+        return Err(Error::new());
+    };
+    ensure!(dst.len() >= m);
+
+    unsafe {
+        let src = src.as_ptr().cast::<u8>();
+        let dst = dst.as_mut_ptr();
+        crate::multiversion::format_hyphenated_batch::auto(src, n, dst, case);
+        Ok(slice_mut(dst, m))
+    }
+}
+
+/// Returns an iterator over the hyphenated UUIDs in `haystack`.
+///
+/// The iterator yields the offset and the bytes of each UUID, from left to right without overlapping.
+/// Hex characters are case-insensitive, and the UUIDs are not required to be delimited.
+#[inline]
+#[must_use]
+pub fn find_iter(haystack: &[u8]) -> FindIter<'_> {
+    FindIter::new(haystack)
+}
+
+#[inline(always)]
+fn write_out<T>(mut dst: Out<'_, T>, value: T) -> &mut T {
+    unsafe {
+        let ptr = dst.as_mut_ptr();
+        ptr.write(value);
+        &mut *ptr
+    }
+}
+
+/// Formats an UUID to a 22-character URL-safe base64 string without padding.
+#[inline]
+#[must_use]
+pub fn format_base64url<'d>(src: &[u8; 16], mut dst: Out<'d, [u8; 22]>) -> &'d mut [u8; 22] {
+    unsafe {
+        let src = src.as_ptr();
+        let dst = dst.as_mut_ptr().cast::<u8>();
+        crate::multiversion::format_base64url::auto(src, dst);
+        &mut *dst.cast()
+    }
+}
+
+/// Parses an UUID from a 22-character URL-safe base64 string without padding.
+///
+/// # Errors
+/// This function returns `Err` if:
+///
+/// + The length of `src` is not 22.
+/// + The content of `src` is invalid, including non-zero trailing bits.
+#[inline]
+pub fn parse_base64url<'d>(src: &[u8], mut dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8; 16], Error> {
+    ensure!(src.len() == 22);
+    unsafe {
+        let src = src.as_ptr();
+        let dst = dst.as_mut_ptr().cast::<u8>();
+        crate::multiversion::parse_base64url::auto(src, dst)?;
+        Ok(&mut *dst.cast())
+    }
+}
+
+/// Formats an UUID to a 26-character Crockford base32 string.
+///
+/// The 128 bits are preceded by 2 zero bits, like the ULID format.
+#[inline]
+#[must_use]
+pub fn format_crockford<'d>(src: &[u8; 16], mut dst: Out<'d, [u8; 26]>, case: AsciiCase) -> &'d mut [u8; 26] {
+    unsafe {
+        let src = src.as_ptr();
+        let dst = dst.as_mut_ptr().cast::<u8>();
+        crate::multiversion::format_crockford::auto(src, dst, case);
+        &mut *dst.cast()
+    }
+}
+
+/// Parses an UUID from a 26-character Crockford base32 string.
+///
+/// The characters are case-insensitive, and `I`, `L`, `O` are read as `1`, `1`, `0`.
+///
+/// # Errors
+/// This function returns `Err` if:
+///
+/// + The length of `src` is not 26.
+/// + The content of `src` is invalid, including a first character greater than `7`.
+#[inline]
+pub fn parse_crockford<'d>(src: &[u8], mut dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8; 16], Error> {
+    ensure!(src.len() == 26);
+    unsafe {
+        let src = src.as_ptr();
+        let dst = dst.as_mut_ptr().cast::<u8>();
+        crate::multiversion::parse_crockford::auto(src, dst)?;
+        Ok(&mut *dst.cast())
+    }
+}
+
+/// Formats an UUID to a 22-character base58 string with the Bitcoin alphabet.
+///
+/// The 128 bits are written as a base58 number, left-padded with `1` (the zero digit) to 22 characters.
+#[inline]
+#[must_use]
+pub fn format_base58<'d>(src: &[u8; 16], dst: Out<'d, [u8; 22]>) -> &'d mut [u8; 22] {
+    let mut buf = [0; 22];
+    crate::compact::format_base58(src, &mut buf);
+    write_out(dst, buf)
+}
+
+/// Parses an UUID from a 22-character base58 string with the Bitcoin alphabet.
+///
+/// # Errors
+/// This function returns `Err` if:
+///
+/// + The length of `src` is not 22.
+/// + The content of `src` is invalid, including a value that does not fit in 128 bits.
+#[inline]
+pub fn parse_base58<'d>(src: &[u8], dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8; 16], Error> {
+    let src: &[u8; 22] = src.try_into().map_err(|_| Error::new())?;
+    let mut buf = [0; 16];
+    crate::compact::parse_base58(src, &mut buf)?;
+    Ok(write_out(dst, buf))
+}
+
+/// Parses an UUID from UTF-16 code units.
+///
+/// The accepted format variants are the same as [`parse`].
+///
+/// # Errors
+/// This function returns `Err` if:
+///
+/// + The length of `src` doesn't match any UUID format variants.
+/// + The content of `src` is invalid.
+#[inline]
+pub fn parse_utf16<'d>(src: &[u16], mut dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8; 16], Error> {
+    let n = src.len();
+
+    if n == 32 {
+        unsafe {
+            let src = src.as_ptr();
+            let dst = dst.as_mut_ptr().cast::<u8>();
+            crate::multiversion::parse_simple_utf16::auto(src, dst)?;
+            return Ok(&mut *dst.cast());
+        }
+    }
+
+    let src = match n {
+        36 => src,
+        // Microsoft GUID
+        38 => {
+            ensure!(src[0] == u16::from(b'{') && src[37] == u16::from(b'}'));
+            &src[1..37]
+        }
+        // URN prefixed UUID
+        45 => {
+            let prefix = b"urn:uuid:";
+            ensure!(src.iter().zip(prefix).all(|(&a, &b)| a == u16::from(b)));
+            &src[9..]
+        }
+        _ => return Err(Error::new()),
+    };
+    unsafe {
+        let src = src.as_ptr();
+        let dst = dst.as_mut_ptr().cast::<u8>();
+        crate::multiversion::parse_hyphenated_utf16::auto(src, dst)?;
+        Ok(&mut *dst.cast())
+    }
+}
+
+/// Formats an UUID to a hyphenated UUID string in UTF-16 code units.
+#[inline]
+#[must_use]
+pub fn format_hyphenated_utf16<'d>(src: &[u8; 16], mut dst: Out<'d, [u16; 36]>, case: AsciiCase) -> &'d mut [u16; 36] {
+    unsafe {
+        let src = src.as_ptr();
+        let dst = dst.as_mut_ptr().cast::<u16>();
+        crate::multiversion::format_hyphenated_utf16::auto(src, dst, case);
+        &mut *dst.cast()
+    }
+}
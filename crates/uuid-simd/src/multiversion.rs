@@ -20,6 +20,24 @@ vsimd::dispatch!(
     fastest     = {"avx2", "neon", "simd128"},
 );
 
+vsimd::dispatch!(
+    name        = {parse_simple_case},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8, case: AsciiCase) -> Result<(), Error>},
+    fallback    = {crate::parse::parse_simple_case_fallback},
+    simd        = {crate::parse::parse_simple_case_simd},
+    targets     = {"avx2", "ssse3", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {parse_hyphenated_case},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8, case: AsciiCase) -> Result<(), Error>},
+    fallback    = {crate::parse::parse_hyphenated_case_fallback},
+    simd        = {crate::parse::parse_hyphenated_case_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
 vsimd::dispatch!(
     name        = {format_simple},
     signature   = {pub unsafe fn(src: *const u8, dst: *mut u8, case: AsciiCase) -> ()},
@@ -37,3 +55,147 @@ vsimd::dispatch!(
     targets     = {"avx2", "sse4.1", "neon", "simd128"},
     fastest     = {"avx2", "neon", "simd128"},
 );
+
+vsimd::dispatch!(
+    name        = {format_braced},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8, case: AsciiCase) -> ()},
+    fallback    = {crate::format::format_braced_fallback},
+    simd        = {crate::format::format_braced_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {format_urn},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8, case: AsciiCase) -> ()},
+    fallback    = {crate::format::format_urn_fallback},
+    simd        = {crate::format::format_urn_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {parse_hyphenated_batch},
+    signature   = {pub unsafe fn(src: *const u8, stride: usize, dst: *mut u8, n: usize, failures: *mut u64) -> usize},
+    fallback    = {crate::parse::parse_hyphenated_batch_fallback},
+    simd        = {crate::parse::parse_hyphenated_batch_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {parse_batch},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, offsets: *const usize, dst: *mut u8, n: usize, failures: *mut u64) -> usize},
+    fallback    = {crate::parse::parse_batch_fallback},
+    simd        = {crate::parse::parse_batch_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {format_hyphenated_batch},
+    signature   = {pub unsafe fn(src: *const u8, n: usize, dst: *mut u8, case: AsciiCase) -> ()},
+    fallback    = {crate::format::format_hyphenated_batch_fallback},
+    simd        = {crate::format::format_hyphenated_batch_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {parse_simple_le},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8) -> Result<(), Error>},
+    fallback    = {crate::parse::parse_simple_le_fallback},
+    simd        = {crate::parse::parse_simple_le_simd},
+    targets     = {"avx2", "ssse3", "sse2", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {parse_hyphenated_le},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8) -> Result<(), Error>},
+    fallback    = {crate::parse::parse_hyphenated_le_fallback},
+    simd        = {crate::parse::parse_hyphenated_le_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {format_hyphenated_le},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8, case: AsciiCase) -> ()},
+    fallback    = {crate::format::format_hyphenated_le_fallback},
+    simd        = {crate::format::format_hyphenated_le_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {find_hyphenated},
+    signature   = {pub unsafe fn(src: *const u8, len: usize, dst: *mut u8) -> usize},
+    fallback    = {crate::parse::find_hyphenated_fallback},
+    simd        = {crate::parse::find_hyphenated_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {parse_simple_utf16},
+    signature   = {pub unsafe fn(src: *const u16, dst: *mut u8) -> Result<(), Error>},
+    fallback    = {crate::utf16::parse_simple_utf16_fallback},
+    simd        = {crate::utf16::parse_simple_utf16_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {parse_hyphenated_utf16},
+    signature   = {pub unsafe fn(src: *const u16, dst: *mut u8) -> Result<(), Error>},
+    fallback    = {crate::utf16::parse_hyphenated_utf16_fallback},
+    simd        = {crate::utf16::parse_hyphenated_utf16_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {format_hyphenated_utf16},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u16, case: AsciiCase) -> ()},
+    fallback    = {crate::utf16::format_hyphenated_utf16_fallback},
+    simd        = {crate::utf16::format_hyphenated_utf16_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {format_base64url},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8) -> ()},
+    fallback    = {crate::compact::format_base64url_fallback},
+    simd        = {crate::compact::format_base64url_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {parse_base64url},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8) -> Result<(), Error>},
+    fallback    = {crate::compact::parse_base64url_fallback},
+    simd        = {crate::compact::parse_base64url_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {format_crockford},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8, case: AsciiCase) -> ()},
+    fallback    = {crate::compact::format_crockford_fallback},
+    simd        = {crate::compact::format_crockford_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
+
+vsimd::dispatch!(
+    name        = {parse_crockford},
+    signature   = {pub unsafe fn(src: *const u8, dst: *mut u8) -> Result<(), Error>},
+    fallback    = {crate::compact::parse_crockford_fallback},
+    simd        = {crate::compact::parse_crockford_simd},
+    targets     = {"avx2", "sse4.1", "neon", "simd128"},
+    fastest     = {"avx2", "neon", "simd128"},
+);
@@ -0,0 +1,163 @@
+use crate::{AsciiCase, Error, Out};
+
+const SIMPLE: u8 = 1 << 0;
+const HYPHENATED: u8 = 1 << 1;
+const BRACED: u8 = 1 << 2;
+const URN: u8 = 1 << 3;
+
+const URN_PREFIX: &[u8; 9] = b"urn:uuid:";
+
+/// Options of UUID parsing.
+///
+/// # Examples
+///
+/// ```
+/// use uuid_simd::{AsOut, ParseOptions};
+///
+/// let options = ParseOptions::new().simple(false).braced(false).lowercase(true);
+///
+/// let mut buf = [0; 16];
+/// assert!(options.parse(b"67e55044-10b1-426f-9247-bb680e5fe0c8", buf.as_out()).is_ok());
+/// assert!(options.parse(b"67E55044-10B1-426F-9247-BB680E5FE0C8", buf.as_out()).is_err());
+/// assert!(options.parse(b"67e5504410b1426f9247bb680e5fe0c8", buf.as_out()).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    forms: u8,
+    lowercase: bool,
+    urn_ignore_case: bool,
+}
+
+impl Default for ParseOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseOptions {
+    /// Creates options which behave the same as [`parse`](crate::parse).
+    ///
+    /// All format variants are allowed, the hex digits are case-insensitive and the URN prefix must be lowercase.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            forms: SIMPLE | HYPHENATED | BRACED | URN,
+            lowercase: false,
+            urn_ignore_case: false,
+        }
+    }
+
+    #[inline(always)]
+    const fn set_form(mut self, form: u8, allow: bool) -> Self {
+        if allow {
+            self.forms |= form;
+        } else {
+            self.forms &= !form;
+        }
+        self
+    }
+
+    /// Allows or denies the "simple" format.
+    #[inline]
+    #[must_use]
+    pub const fn simple(self, allow: bool) -> Self {
+        self.set_form(SIMPLE, allow)
+    }
+
+    /// Allows or denies the "hyphenated" format.
+    #[inline]
+    #[must_use]
+    pub const fn hyphenated(self, allow: bool) -> Self {
+        self.set_form(HYPHENATED, allow)
+    }
+
+    /// Allows or denies the "braced" format (Microsoft GUID).
+    #[inline]
+    #[must_use]
+    pub const fn braced(self, allow: bool) -> Self {
+        self.set_form(BRACED, allow)
+    }
+
+    /// Allows or denies the "urn" format.
+    #[inline]
+    #[must_use]
+    pub const fn urn(self, allow: bool) -> Self {
+        self.set_form(URN, allow)
+    }
+
+    /// Requires the hex digits to be lowercase.
+    #[inline]
+    #[must_use]
+    pub const fn lowercase(mut self, require: bool) -> Self {
+        self.lowercase = require;
+        self
+    }
+
+    /// Accepts the URN prefix case-insensitively, such as `URN:UUID:`.
+    #[inline]
+    #[must_use]
+    pub const fn urn_ignore_case(mut self, accept: bool) -> Self {
+        self.urn_ignore_case = accept;
+        self
+    }
+
+    /// Parses an UUID from arbitrary bytes with the options.
+    ///
+    /// # Errors
+    /// This function returns `Err` if:
+    ///
+    /// + The length of `src` doesn't match any allowed UUID format variants.
+    /// + The content of `src` is invalid.
+    #[inline]
+    pub fn parse<'d>(&self, src: &[u8], mut dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8; 16], Error> {
+        let allows = |form: u8| self.forms & form != 0;
+
+        let (body, simple) = match src.len() {
+            32 => {
+                ensure!(allows(SIMPLE));
+                (src, true)
+            }
+            36 => {
+                ensure!(allows(HYPHENATED));
+                (src, false)
+            }
+            38 => {
+                ensure!(allows(BRACED) && src[0] == b'{' && src[37] == b'}');
+                (&src[1..37], false)
+            }
+            45 => {
+                let (prefix, rest) = src.split_at(9);
+                let prefix_ok = if self.urn_ignore_case {
+                    prefix.eq_ignore_ascii_case(URN_PREFIX)
+                } else {
+                    prefix == URN_PREFIX
+                };
+                ensure!(allows(URN) && prefix_ok);
+                (rest, false)
+            }
+            _ => return Err(Error::new()),
+        };
+
+        if !self.lowercase {
+            return if simple {
+                crate::parse_simple(body, dst)
+            } else {
+                crate::parse_hyphenated(body, dst)
+            };
+        }
+
+        // The case is checked in the same pass as the hex digits.
+        unsafe {
+            let src = body.as_ptr();
+            let dst = dst.as_mut_ptr().cast::<u8>();
+            if simple {
+                crate::multiversion::parse_simple_case::auto(src, dst, AsciiCase::Lower)?;
+            } else {
+                crate::multiversion::parse_hyphenated_case::auto(src, dst, AsciiCase::Lower)?;
+            }
+            Ok(&mut *dst.cast())
+        }
+    }
+}
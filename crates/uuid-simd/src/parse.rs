@@ -1,11 +1,11 @@
 use crate::spec::*;
-use crate::Error;
+use crate::{AsciiCase, Error};
 
-use vsimd::hex::unhex;
+use vsimd::hex::{unhex, unhex_case};
 use vsimd::is_isa_type;
 use vsimd::isa::{InstructionSet, SSE2};
 use vsimd::tools::{read, write};
-use vsimd::vector::{V256, V64};
+use vsimd::vector::{V128, V256, V64};
 use vsimd::{SIMD128, SIMD256};
 
 #[inline(always)]
@@ -14,7 +14,7 @@ const fn shl4(x: u8) -> u8 {
 }
 
 #[inline(always)]
-pub unsafe fn parse_simple_fallback(src: *const u8, dst: *mut u8) -> Result<(), Error> {
+unsafe fn parse_simple_fallback_with(src: *const u8, dst: *mut u8, unhex: impl Fn(u8) -> u8) -> Result<(), Error> {
     let mut flag = 0;
     for i in 0..16 {
         let h1 = unhex(read(src, i * 2));
@@ -27,7 +27,17 @@ pub unsafe fn parse_simple_fallback(src: *const u8, dst: *mut u8) -> Result<(),
 }
 
 #[inline(always)]
-pub unsafe fn parse_hyphenated_fallback(src: *const u8, dst: *mut u8) -> Result<(), Error> {
+pub unsafe fn parse_simple_fallback(src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    parse_simple_fallback_with(src, dst, unhex)
+}
+
+#[inline(always)]
+pub unsafe fn parse_simple_case_fallback(src: *const u8, dst: *mut u8, case: AsciiCase) -> Result<(), Error> {
+    parse_simple_fallback_with(src, dst, |x| unhex_case(x, case))
+}
+
+#[inline(always)]
+unsafe fn parse_hyphenated_fallback_with(src: *const u8, dst: *mut u8, unhex: impl Fn(u8) -> u8) -> Result<(), Error> {
     match [read(src, 8), read(src, 13), read(src, 18), read(src, 23)] {
         [b'-', b'-', b'-', b'-'] => {}
         _ => return Err(Error::new()),
@@ -49,25 +59,113 @@ pub unsafe fn parse_hyphenated_fallback(src: *const u8, dst: *mut u8) -> Result<
 }
 
 #[inline(always)]
-pub unsafe fn parse_simple_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
+pub unsafe fn parse_hyphenated_fallback(src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    parse_hyphenated_fallback_with(src, dst, unhex)
+}
+
+#[inline(always)]
+pub unsafe fn parse_hyphenated_case_fallback(src: *const u8, dst: *mut u8, case: AsciiCase) -> Result<(), Error> {
+    parse_hyphenated_fallback_with(src, dst, |x| unhex_case(x, case))
+}
+
+#[inline(always)]
+pub unsafe fn parse_simple_le_fallback(src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    parse_simple_fallback(src, dst)?;
+    guid_le_swap(dst, dst);
+    Ok(())
+}
+
+#[inline(always)]
+pub unsafe fn parse_hyphenated_le_fallback(src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    parse_hyphenated_fallback(src, dst)?;
+    guid_le_swap(dst, dst);
+    Ok(())
+}
+
+#[inline(always)]
+unsafe fn parse_simple_simd_impl<S: SIMD256, const LE: bool>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
     if is_isa_type!(S, SSE2) {
-        return parse_simple_simd_sse2(SSE2::new(), src, dst);
+        parse_simple_simd_sse2(SSE2::new(), src, dst)?;
+        if LE {
+            guid_le_swap(dst, dst);
+        }
+        return Ok(());
     }
     {
         let x = s.v256_load_unaligned(src);
-        let y = try_!(vsimd::hex::decode_ascii32(s, x));
+        let mut y = try_!(vsimd::hex::decode_ascii32(s, x));
+        if LE {
+            y = s.u8x16_swizzle(y, GUID_LE_SWIZZLE);
+        }
         s.v128_store_unaligned(dst, y);
         Ok(())
     }
 }
 
+#[inline(always)]
+pub unsafe fn parse_simple_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    parse_simple_simd_impl::<S, false>(s, src, dst)
+}
+
+#[inline(always)]
+pub unsafe fn parse_simple_le_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    parse_simple_simd_impl::<S, true>(s, src, dst)
+}
+
 #[inline(always)]
 pub unsafe fn parse_hyphenated_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    parse_hyphenated_simd_impl::<S, false>(s, src, dst)
+}
+
+#[inline(always)]
+pub unsafe fn parse_hyphenated_le_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u8) -> Result<(), Error> {
+    parse_hyphenated_simd_impl::<S, true>(s, src, dst)
+}
+
+#[inline(always)]
+pub unsafe fn parse_simple_case_simd<S: SIMD256>(
+    s: S,
+    src: *const u8,
+    dst: *mut u8,
+    case: AsciiCase,
+) -> Result<(), Error> {
+    let x = s.v256_load_unaligned(src);
+    let y = try_!(vsimd::hex::decode_case_ascii32(s, x, case));
+    s.v128_store_unaligned(dst, y);
+    Ok(())
+}
+
+#[inline(always)]
+pub unsafe fn parse_hyphenated_case_simd<S: SIMD256>(
+    s: S,
+    src: *const u8,
+    dst: *mut u8,
+    case: AsciiCase,
+) -> Result<(), Error> {
+    let a = load_hyphenated(s, src)?;
+    let y = try_!(vsimd::hex::decode_case_ascii32(s, a, case));
+    s.v128_store_unaligned(dst, y);
+    Ok(())
+}
+
+/// Checks the hyphens and gathers the 32 hex characters of a hyphenated UUID.
+#[inline(always)]
+unsafe fn load_hyphenated<S: SIMD256>(s: S, src: *const u8) -> Result<V256, Error> {
     match [read(src, 8), read(src, 13), read(src, 18), read(src, 23)] {
         [b'-', b'-', b'-', b'-'] => {}
         _ => return Err(Error::new()),
     }
 
+    let a0 = s.v256_load_unaligned(src);
+    let mid = src.add(16).cast::<i16>().read_unaligned();
+    let tail = src.add(32).cast::<i32>().read_unaligned();
+    Ok(gather_hyphenated(s, a0, mid, tail))
+}
+
+/// Gathers the 32 hex characters of a hyphenated UUID
+/// from its first 32 characters (`a0`), the characters at 16..18 (`mid`) and the last 4 characters (`tail`).
+#[inline(always)]
+pub fn gather_hyphenated<S: SIMD256>(s: S, a0: V256, mid: i16, tail: i32) -> V256 {
     const SWIZZLE: V256 = V256::from_bytes([
         0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, //
         0x09, 0x0a, 0x0b, 0x0c, 0x0e, 0x0f, 0x80, 0x80, //
@@ -75,13 +173,22 @@ pub unsafe fn parse_hyphenated_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut
         0x0c, 0x0d, 0x0e, 0x0f, 0x80, 0x80, 0x80, 0x80, //
     ]);
 
-    let a0 = s.v256_load_unaligned(src);
     let a1 = s.u8x16x2_swizzle(a0, SWIZZLE);
+    let a2 = i16x16_set_lane7(s, a1, mid);
+    i32x8_set_lane7(s, a2, tail)
+}
 
-    let a2 = i16x16_set_lane7(s, a1, src.add(16).cast::<i16>().read_unaligned());
-    let a3 = i32x8_set_lane7(s, a2, src.add(32).cast::<i32>().read_unaligned());
-
-    let ans = try_!(vsimd::hex::decode_ascii32(s, a3));
+#[inline(always)]
+unsafe fn parse_hyphenated_simd_impl<S: SIMD256, const LE: bool>(
+    s: S,
+    src: *const u8,
+    dst: *mut u8,
+) -> Result<(), Error> {
+    let a = load_hyphenated(s, src)?;
+    let mut ans = try_!(vsimd::hex::decode_ascii32(s, a));
+    if LE {
+        ans = s.u8x16_swizzle(ans, GUID_LE_SWIZZLE);
+    }
     s.v128_store_unaligned(dst, ans);
 
     Ok(())
@@ -91,7 +198,12 @@ pub unsafe fn parse_hyphenated_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut
 pub unsafe fn parse_simple_simd_sse2(s: SSE2, src: *const u8, dst: *mut u8) -> Result<(), Error> {
     let x1 = s.v128_load_unaligned(src);
     let x2 = s.v128_load_unaligned(src.add(16));
+    decode_simple_sse2(s, x1, x2, dst)
+}
 
+/// Decodes the 32 hex characters of a simple UUID, given as two halves.
+#[inline(always)]
+pub unsafe fn decode_simple_sse2(s: SSE2, x1: V128, x2: V128, dst: *mut u8) -> Result<(), Error> {
     let (n1, f1) = vsimd::hex::sse2::decode_nibbles(s, x1);
     let (n2, f2) = vsimd::hex::sse2::decode_nibbles(s, x2);
 
@@ -104,3 +216,213 @@ pub unsafe fn parse_simple_simd_sse2(s: SSE2, src: *const u8, dst: *mut u8) -> R
     dst.cast::<[V64; 2]>().write_unaligned([y1, y2]);
     Ok(())
 }
+
+/// Marks the row `i` as failed and zeroes its output.
+#[inline(always)]
+unsafe fn mark_failure(i: usize, dst: *mut u8, failures: *mut u64) {
+    dst.add(i * 16).write_bytes(0, 16);
+    *failures.add(i / 64) |= 1 << (i % 64);
+}
+
+/// Locates the hex characters of the row `i` in an offset-based batch.
+///
+/// Returns the start of the body and whether it is hyphenated.
+#[inline(always)]
+unsafe fn batch_row(src: *const u8, len: usize, offsets: *const usize, i: usize) -> Result<(*const u8, bool), Error> {
+    let start = read(offsets, i);
+    let end = read(offsets, i + 1);
+    ensure!(start <= end && end <= len);
+
+    let p = src.add(start);
+    match end - start {
+        32 => Ok((p, false)),
+        36 => Ok((p, true)),
+        // Microsoft GUID
+        38 => {
+            ensure!(read(p, 0) == b'{' && read(p, 37) == b'}');
+            Ok((p.add(1), true))
+        }
+        // URN prefixed UUID
+        45 => {
+            ensure!(p.cast::<[u8; 9]>().read_unaligned() == *URN_PREFIX);
+            Ok((p.add(9), true))
+        }
+        _ => Err(Error::new()),
+    }
+}
+
+#[inline(always)]
+unsafe fn parse_rows_fallback(
+    n: usize,
+    dst: *mut u8,
+    failures: *mut u64,
+    row: impl Fn(usize) -> Result<(*const u8, bool), Error>,
+) -> usize {
+    let mut count = 0;
+    for i in 0..n {
+        let ans = row(i).and_then(|(src, hyphenated)| {
+            if hyphenated {
+                parse_hyphenated_fallback(src, dst.add(i * 16))
+            } else {
+                parse_simple_fallback(src, dst.add(i * 16))
+            }
+        });
+        if ans.is_err() {
+            mark_failure(i, dst, failures);
+            count += 1;
+        }
+    }
+    count
+}
+
+#[inline(always)]
+pub unsafe fn parse_hyphenated_batch_fallback(
+    src: *const u8,
+    stride: usize,
+    dst: *mut u8,
+    n: usize,
+    failures: *mut u64,
+) -> usize {
+    parse_rows_fallback(n, dst, failures, |i| Ok((src.add(i * stride), true)))
+}
+
+#[inline(always)]
+pub unsafe fn parse_batch_fallback(
+    src: *const u8,
+    len: usize,
+    offsets: *const usize,
+    dst: *mut u8,
+    n: usize,
+    failures: *mut u64,
+) -> usize {
+    parse_rows_fallback(n, dst, failures, |i| batch_row(src, len, offsets, i))
+}
+
+/// Gathers the 32 hex characters of a row.
+#[inline(always)]
+unsafe fn load_row<S: SIMD256>(s: S, row: Result<(*const u8, bool), Error>) -> Result<V256, Error> {
+    let (src, hyphenated) = row?;
+    if hyphenated {
+        load_hyphenated(s, src)
+    } else {
+        Ok(s.v256_load_unaligned(src))
+    }
+}
+
+/// Decodes two rows per iteration.
+///
+/// A failed pair is decoded again row by row to find the invalid rows.
+#[inline(always)]
+unsafe fn parse_rows_simd<S: SIMD256>(
+    s: S,
+    n: usize,
+    dst: *mut u8,
+    failures: *mut u64,
+    row: impl Fn(usize) -> Result<(*const u8, bool), Error>,
+) -> usize {
+    let parse_one = |i: usize| -> usize {
+        let ans = load_row(s, row(i)).and_then(|x| {
+            let y = try_!(vsimd::hex::decode_ascii32(s, x));
+            s.v128_store_unaligned(dst.add(i * 16), y);
+            Ok(())
+        });
+        if ans.is_err() {
+            mark_failure(i, dst, failures);
+            return 1;
+        }
+        0
+    };
+
+    let mut count = 0;
+    let mut i = 0;
+    while i + 2 <= n {
+        let x1 = load_row(s, row(i));
+        let x2 = load_row(s, row(i + 1));
+        if let (Ok(x1), Ok(x2)) = (x1, x2) {
+            if let Ok(y) = vsimd::hex::decode_ascii32x2(s, (x1, x2)) {
+                s.v256_store_unaligned(dst.add(i * 16), y);
+                i += 2;
+                continue;
+            }
+        }
+        count += parse_one(i) + parse_one(i + 1);
+        i += 2;
+    }
+    if i < n {
+        count += parse_one(i);
+    }
+    count
+}
+
+#[inline(always)]
+pub unsafe fn parse_hyphenated_batch_simd<S: SIMD256>(
+    s: S,
+    src: *const u8,
+    stride: usize,
+    dst: *mut u8,
+    n: usize,
+    failures: *mut u64,
+) -> usize {
+    parse_rows_simd(s, n, dst, failures, |i| Ok((src.add(i * stride), true)))
+}
+
+#[inline(always)]
+pub unsafe fn parse_batch_simd<S: SIMD256>(
+    s: S,
+    src: *const u8,
+    len: usize,
+    offsets: *const usize,
+    dst: *mut u8,
+    n: usize,
+    failures: *mut u64,
+) -> usize {
+    parse_rows_simd(s, n, dst, failures, |i| batch_row(src, len, offsets, i))
+}
+
+/// Returns the offset of the first hyphenated UUID and writes its bytes to `dst`,
+/// or `usize::MAX` if there is no UUID.
+#[inline(always)]
+pub unsafe fn find_hyphenated_fallback(src: *const u8, len: usize, dst: *mut u8) -> usize {
+    let mut i = 0;
+    while i + 36 <= len {
+        let p = src.add(i);
+        if read(p, 8) == b'-' && parse_hyphenated_fallback(p, dst).is_ok() {
+            return i;
+        }
+        i += 1;
+    }
+    usize::MAX
+}
+
+#[inline(always)]
+pub unsafe fn find_hyphenated_simd<S: SIMD256>(s: S, src: *const u8, len: usize, dst: *mut u8) -> usize {
+    let dash = s.u8x32_splat(b'-');
+
+    // Each iteration checks the 32 candidates starting at `src + i`.
+    // The last candidate ends at `src + i + 31 + 36`, the tail is left to the fallback.
+    let mut i = 0;
+    while i + 67 <= len {
+        let base = src.add(i);
+        let d1 = s.u8x32_eq(s.v256_load_unaligned(base.add(8)), dash);
+        let d2 = s.u8x32_eq(s.v256_load_unaligned(base.add(13)), dash);
+        let d3 = s.u8x32_eq(s.v256_load_unaligned(base.add(18)), dash);
+        let d4 = s.u8x32_eq(s.v256_load_unaligned(base.add(23)), dash);
+        let hyphens = s.v256_and(s.v256_and(d1, d2), s.v256_and(d3, d4));
+
+        let mut mask = s.u8x32_bitmask(hyphens);
+        while mask != 0 {
+            let k = mask.trailing_zeros() as usize;
+            if parse_hyphenated_simd(s, base.add(k), dst).is_ok() {
+                return i + k;
+            }
+            mask &= mask - 1;
+        }
+
+        i += 32;
+    }
+
+    match find_hyphenated_fallback(src.add(i), len - i, dst) {
+        usize::MAX => usize::MAX,
+        k => i + k,
+    }
+}
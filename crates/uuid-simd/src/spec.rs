@@ -37,6 +37,25 @@ use core::arch::aarch64::*;
 #[cfg(target_arch = "wasm32")]
 use core::arch::wasm32::*;
 
+/// The prefix of the URN format.
+pub const URN_PREFIX: &[u8; 9] = b"urn:uuid:";
+
+/// Swaps the byte order of the first three fields (Microsoft GUID layout).
+pub const GUID_LE_SWIZZLE: V128 = V128::from_bytes([
+    0x03, 0x02, 0x01, 0x00, 0x05, 0x04, 0x07, 0x06, //
+    0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, //
+]);
+
+#[inline(always)]
+pub unsafe fn guid_le_swap(src: *const u8, dst: *mut u8) {
+    let x = src.cast::<[u8; 16]>().read_unaligned();
+    let y = [
+        x[3], x[2], x[1], x[0], x[5], x[4], x[7], x[6], //
+        x[8], x[9], x[10], x[11], x[12], x[13], x[14], x[15],
+    ];
+    dst.cast::<[u8; 16]>().write_unaligned(y);
+}
+
 #[inline(always)]
 pub fn i16x16_set_lane7<S: SIMD256>(s: S, a: V256, x: i16) -> V256 {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -140,3 +159,26 @@ pub fn i16x8_get_lane7<S: SIMD128>(s: S, a: V128) -> i16 {
         unreachable!()
     }
 }
+
+/// Narrows the 16-bit lanes of `a` and `b` to bytes with saturation.
+///
+/// Lanes above `0xff` become `0x00` or `0xff`, neither of which is a hex digit or a hyphen.
+#[inline(always)]
+pub fn u16x8x2_narrow_sat<S: SIMD128>(s: S, a: V128, b: V128) -> V128 {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if matches_isa!(S, SSE2) {
+        return unsafe { t(_mm_packus_epi16(t(a), t(b))) };
+    }
+    #[cfg(any(all(feature = "unstable", target_arch = "arm"), target_arch = "aarch64"))]
+    if matches_isa!(S, NEON) {
+        return unsafe { t(vcombine_u8(vqmovn_u16(t(a)), vqmovn_u16(t(b)))) };
+    }
+    #[cfg(target_arch = "wasm32")]
+    if matches_isa!(S, WASM128) {
+        return unsafe { t(u8x16_narrow_i16x8(t(a), t(b))) };
+    }
+    {
+        let _ = (s, a, b);
+        unreachable!()
+    }
+}
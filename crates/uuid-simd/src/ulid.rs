@@ -0,0 +1,154 @@
+//! ULID operations.
+//!
+//! A ULID is a 48-bit big-endian Unix timestamp in milliseconds followed by 80 random bits.
+//! Its string form is 26 Crockford base32 characters.
+//!
+//! The binary layout of a ULID is the same as a UUID,
+//! so the bytes can be stored in UUID columns directly.
+//!
+//! # Examples
+//!
+//! ```
+//! use uuid_simd::{ulid, AsOut, AsciiCase};
+//!
+//! let text = "01ARZ3NDEKTSV4RRFFQ69G5FAV";
+//! let mut buf = [0; 16];
+//! let bytes = ulid::parse(text.as_bytes(), buf.as_out()).unwrap();
+//! assert_eq!(ulid::timestamp(bytes), 1_469_922_850_259);
+//!
+//! let mut buf = [0; 26];
+//! assert_eq!(ulid::format(bytes, buf.as_out(), AsciiCase::Upper), text.as_bytes());
+//! ```
+
+use crate::{AsciiCase, Error, Out, RandomSource};
+
+/// Parses a ULID from a 26-character string.
+///
+/// The characters are case-insensitive, and `I`, `L`, `O` are read as `1`, `1`, `0`.
+///
+/// # Errors
+/// This function returns `Err` if:
+///
+/// + The length of `src` is not 26.
+/// + The content of `src` is invalid, including a first character greater than `7`.
+#[inline]
+pub fn parse<'d>(src: &[u8], dst: Out<'d, [u8; 16]>) -> Result<&'d mut [u8; 16], Error> {
+    crate::parse_crockford(src, dst)
+}
+
+/// Formats a ULID to a 26-character string.
+#[inline]
+#[must_use]
+pub fn format<'d>(src: &[u8; 16], dst: Out<'d, [u8; 26]>, case: AsciiCase) -> &'d mut [u8; 26] {
+    crate::format_crockford(src, dst, case)
+}
+
+/// Returns the timestamp of a ULID in milliseconds since the Unix epoch.
+#[inline]
+#[must_use]
+pub const fn timestamp(ulid: &[u8; 16]) -> u64 {
+    let mut ans = 0;
+    let mut i = 0;
+    while i < 6 {
+        ans = (ans << 8) | ulid[i] as u64;
+        i += 1;
+    }
+    ans
+}
+
+/// Converts a ULID to a v7 UUID.
+///
+/// The timestamp is kept. The version and variant bits overwrite 6 random bits,
+/// so the conversion is not reversible.
+#[inline]
+#[must_use]
+pub const fn to_uuid_v7(ulid: &[u8; 16]) -> [u8; 16] {
+    let mut bytes = *ulid;
+    bytes[6] = (bytes[6] & 0x0f) | 0x70;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    bytes
+}
+
+/// Converts a UUID to a ULID by reinterpreting its bytes.
+///
+/// A v7 UUID becomes a ULID with the same timestamp.
+#[inline]
+#[must_use]
+pub const fn from_uuid(uuid: &[u8; 16]) -> [u8; 16] {
+    *uuid
+}
+
+const RANDOM_MAX: u128 = (1 << 80) - 1;
+
+/// A monotonic ULID generator.
+///
+/// In the same millisecond, the random part of the previous ULID is incremented by one,
+/// so the ULIDs from one generator are strictly increasing.
+/// A clock that goes backwards is reported as an error instead of being hidden.
+#[derive(Debug, Default, Clone)]
+pub struct Generator {
+    /// The timestamp of the previous ULID, or `None` before the first one.
+    last_ms: Option<u64>,
+    last_random: u128,
+}
+
+impl Generator {
+    /// Creates a ULID generator.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            last_ms: None,
+            last_random: 0,
+        }
+    }
+
+    /// Generates a ULID with `unix_ms`, the count of milliseconds since the Unix epoch.
+    ///
+    /// # Errors
+    /// This function returns `Err` if:
+    ///
+    /// + `unix_ms` does not fit in 48 bits.
+    /// + `unix_ms` is earlier than the timestamp of the previous ULID.
+    /// + The random part overflows in the same millisecond.
+    #[inline]
+    pub fn generate<R: RandomSource + ?Sized>(&mut self, unix_ms: u64, rng: &mut R) -> Result<[u8; 16], Error> {
+        ensure!(unix_ms < (1 << 48));
+
+        let same_ms = match self.last_ms {
+            Some(last_ms) => {
+                ensure!(unix_ms >= last_ms);
+                unix_ms == last_ms
+            }
+            None => false,
+        };
+
+        if same_ms {
+            ensure!(self.last_random < RANDOM_MAX);
+            self.last_random += 1;
+        } else {
+            let mut buf = [0u8; 16];
+            rng.fill_bytes(&mut buf[6..]);
+            self.last_ms = Some(unix_ms);
+            self.last_random = u128::from_be_bytes(buf);
+        }
+
+        Ok(((unix_ms as u128) << 80 | self.last_random).to_be_bytes())
+    }
+
+    /// Generates a ULID and formats it to a 26-character string.
+    ///
+    /// # Errors
+    /// This function returns `Err` if [`Generator::generate`] fails.
+    #[inline]
+    pub fn format<'d, R: RandomSource + ?Sized>(
+        &mut self,
+        unix_ms: u64,
+        rng: &mut R,
+        dst: Out<'d, [u8; 26]>,
+        case: AsciiCase,
+    ) -> Result<&'d mut [u8; 26], Error> {
+        let ulid = self.generate(unix_ms, rng)?;
+        Ok(format(&ulid, dst, case))
+    }
+}
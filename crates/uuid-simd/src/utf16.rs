@@ -0,0 +1,104 @@
+use crate::format::{format_hyphenated_fallback, format_hyphenated_simd};
+use crate::parse::{decode_simple_sse2, gather_hyphenated, parse_hyphenated_fallback, parse_simple_fallback};
+use crate::spec::u16x8x2_narrow_sat;
+use crate::Error;
+
+use vsimd::ascii::AsciiCase;
+use vsimd::is_isa_type;
+use vsimd::isa::{InstructionSet, SSE2};
+use vsimd::tools::{read, write};
+use vsimd::vector::{V128, V256};
+use vsimd::SIMD256;
+
+#[inline(always)]
+unsafe fn narrow_fallback(src: *const u16, len: usize, dst: *mut u8) -> Result<(), Error> {
+    let mut flag = 0;
+    for i in 0..len {
+        let x = read(src, i);
+        flag |= x;
+        write(dst, i, x as u8);
+    }
+    ensure!(flag < 0x80);
+    Ok(())
+}
+
+#[inline(always)]
+unsafe fn widen_fallback(src: *const u8, len: usize, dst: *mut u16) {
+    for i in 0..len {
+        write(dst, i, read(src, i) as u16);
+    }
+}
+
+#[inline(always)]
+pub unsafe fn parse_simple_utf16_fallback(src: *const u16, dst: *mut u8) -> Result<(), Error> {
+    let mut buf = [0u8; 32];
+    narrow_fallback(src, 32, buf.as_mut_ptr())?;
+    parse_simple_fallback(buf.as_ptr(), dst)
+}
+
+#[inline(always)]
+pub unsafe fn parse_hyphenated_utf16_fallback(src: *const u16, dst: *mut u8) -> Result<(), Error> {
+    let mut buf = [0u8; 36];
+    narrow_fallback(src, 36, buf.as_mut_ptr())?;
+    parse_hyphenated_fallback(buf.as_ptr(), dst)
+}
+
+#[inline(always)]
+pub unsafe fn format_hyphenated_utf16_fallback(src: *const u8, dst: *mut u16, case: AsciiCase) {
+    let mut buf = [0u8; 36];
+    format_hyphenated_fallback(src, buf.as_mut_ptr(), case);
+    widen_fallback(buf.as_ptr(), 36, dst);
+}
+
+/// Loads 32 code units and narrows them to bytes.
+/// Code units above `0xff` saturate to bytes that are rejected by the parser.
+#[inline(always)]
+unsafe fn load_narrow32<S: SIMD256>(s: S, src: *const u16) -> (V128, V128) {
+    let x0 = s.v128_load_unaligned(src.cast());
+    let x1 = s.v128_load_unaligned(src.add(8).cast());
+    let x2 = s.v128_load_unaligned(src.add(16).cast());
+    let x3 = s.v128_load_unaligned(src.add(24).cast());
+    (u16x8x2_narrow_sat(s, x0, x1), u16x8x2_narrow_sat(s, x2, x3))
+}
+
+#[inline(always)]
+pub unsafe fn parse_simple_utf16_simd<S: SIMD256>(s: S, src: *const u16, dst: *mut u8) -> Result<(), Error> {
+    let (x1, x2) = load_narrow32(s, src);
+    if is_isa_type!(S, SSE2) {
+        return decode_simple_sse2(SSE2::new(), x1, x2, dst);
+    }
+    let y = try_!(vsimd::hex::decode_ascii32(s, V256::from_v128x2((x1, x2))));
+    s.v128_store_unaligned(dst, y);
+    Ok(())
+}
+
+#[inline(always)]
+pub unsafe fn parse_hyphenated_utf16_simd<S: SIMD256>(s: S, src: *const u16, dst: *mut u8) -> Result<(), Error> {
+    const HYPHEN: u16 = b'-' as u16;
+    ensure!([read(src, 8), read(src, 13), read(src, 18), read(src, 23)] == [HYPHEN; 4]);
+
+    let mut rest = [0u8; 6];
+    narrow_fallback(src.add(16), 2, rest.as_mut_ptr())?;
+    narrow_fallback(src.add(32), 4, rest.as_mut_ptr().add(2))?;
+    let mid = i16::from_ne_bytes([rest[0], rest[1]]);
+    let tail = i32::from_ne_bytes([rest[2], rest[3], rest[4], rest[5]]);
+
+    let a0 = V256::from_v128x2(load_narrow32(s, src));
+    let a = gather_hyphenated(s, a0, mid, tail);
+    let y = try_!(vsimd::hex::decode_ascii32(s, a));
+    s.v128_store_unaligned(dst, y);
+    Ok(())
+}
+
+#[inline(always)]
+pub unsafe fn format_hyphenated_utf16_simd<S: SIMD256>(s: S, src: *const u8, dst: *mut u16, case: AsciiCase) {
+    let mut buf = [0u8; 36];
+    format_hyphenated_simd(s, src, buf.as_mut_ptr(), case);
+
+    let buf = buf.as_ptr();
+    let y0 = s.u16x16_from_u8x16(s.v128_load_unaligned(buf));
+    let y1 = s.u16x16_from_u8x16(s.v128_load_unaligned(buf.add(16)));
+    s.v256_store_unaligned(dst.cast(), y0);
+    s.v256_store_unaligned(dst.add(16).cast(), y1);
+    widen_fallback(buf.add(32), 4, dst.add(32));
+}
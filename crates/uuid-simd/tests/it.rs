@@ -107,5 +107,846 @@ fn basic() {
         assert_eq!(output.as_slice(), expected.to_ascii_uppercase().as_bytes());
         let output = uuid_simd::format_hyphenated(&src, output_buf.as_out(), AsciiCase::Lower);
         assert_eq!(output.as_slice(), expected.to_ascii_lowercase().as_bytes());
+
+        let mut output_buf = [0; 38];
+        let output = uuid_simd::format_braced(&src, output_buf.as_out(), AsciiCase::Upper);
+        assert_eq!(
+            output.as_slice(),
+            format!("{{{}}}", expected.to_ascii_uppercase()).as_bytes()
+        );
+        let output = uuid_simd::format_braced(&src, output_buf.as_out(), AsciiCase::Lower);
+        assert_eq!(
+            output.as_slice(),
+            format!("{{{}}}", expected.to_ascii_lowercase()).as_bytes()
+        );
+
+        let mut output_buf = [0; 45];
+        let output = uuid_simd::format_urn(&src, output_buf.as_out(), AsciiCase::Upper);
+        assert_eq!(
+            output.as_slice(),
+            format!("urn:uuid:{}", expected.to_ascii_uppercase()).as_bytes()
+        );
+        let output = uuid_simd::format_urn(&src, output_buf.as_out(), AsciiCase::Lower);
+        assert_eq!(
+            output.as_slice(),
+            format!("urn:uuid:{}", expected.to_ascii_lowercase()).as_bytes()
+        );
+
+        let mut parsed = [0; 16];
+        assert_eq!(uuid_simd::parse(output, parsed.as_out()).unwrap(), &src);
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn batch() {
+    let mut expected = Vec::new();
+    let mut text = Vec::new();
+    for i in 0..150_usize {
+        let mut uuid = [0u8; 16];
+        uuid[..8].copy_from_slice(&(i as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15).to_le_bytes());
+        uuid[8..].copy_from_slice(&(i as u64).to_be_bytes());
+
+        let mut buf = [0; 36];
+        text.extend_from_slice(uuid_simd::format_hyphenated(&uuid, buf.as_out(), AsciiCase::Lower));
+        text.push(b'\n');
+        expected.push(uuid);
+    }
+
+    let invalid = [3, 64, 100, 149];
+    for &i in &invalid {
+        text[i * 37 + 8] = b'x';
+    }
+
+    let mut dst = vec![[0xff; 16]; expected.len()];
+    let mut failures = [u64::MAX; 3];
+    let count = uuid_simd::parse_hyphenated_batch(&text, 37, &mut dst, &mut failures).unwrap();
+    assert_eq!(count, invalid.len());
+    for (i, uuid) in dst.iter().enumerate() {
+        let failed = failures[i / 64] & (1 << (i % 64)) != 0;
+        assert_eq!(failed, invalid.contains(&i), "i = {i}");
+        if failed {
+            assert_eq!(*uuid, [0; 16]);
+        } else {
+            assert_eq!(*uuid, expected[i]);
+        }
+    }
+
+    assert!(uuid_simd::parse_hyphenated_batch(&text, 36, &mut dst, &mut failures).is_ok());
+    assert!(uuid_simd::parse_hyphenated_batch(&text, 35, &mut dst, &mut failures).is_err());
+    assert!(uuid_simd::parse_hyphenated_batch(&text[..text.len() - 2], 37, &mut dst, &mut failures).is_err());
+    assert!(uuid_simd::parse_hyphenated_batch(&text, 37, &mut dst, &mut failures[..2]).is_err());
+
+    let mut buf = vec![0; expected.len() * 36];
+    let formatted =
+        uuid_simd::format_hyphenated_batch(&expected, buf.as_mut_slice().as_out(), AsciiCase::Upper).unwrap();
+    for (i, row) in formatted.chunks_exact(36).enumerate() {
+        let mut buf = [0; 36];
+        let ans = uuid_simd::format_hyphenated(&expected[i], buf.as_out(), AsciiCase::Upper);
+        assert_eq!(row, ans.as_slice());
+    }
+    assert!(uuid_simd::format_hyphenated_batch(&expected, buf[..35].as_out(), AsciiCase::Upper).is_err());
+
+    let rows = [
+        "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "67e5504410b1426f9247bb680e5fe0c8",
+        "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+        "67e5504410b1426f9247bb680e5fe0cg",
+        "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ];
+    let src = rows.concat();
+    let mut offsets = vec![0];
+    for row in rows {
+        offsets.push(offsets.last().unwrap() + row.len());
+    }
+    offsets.push(src.len() + 1);
+
+    let mut dst = [[0xff; 16]; 6];
+    let mut failures = [0];
+    let count = uuid_simd::parse_batch(src.as_bytes(), &offsets, &mut dst, &mut failures).unwrap();
+    assert_eq!(count, 2);
+    assert_eq!(failures[0], 0b10_1000);
+    assert_eq!(dst[0], dst[1]);
+    assert_eq!(dst[0], dst[2]);
+    assert_eq!(dst[0], dst[4]);
+    assert_eq!(dst[3], [0; 16]);
+    assert_eq!(dst[5], [0; 16]);
+
+    assert!(uuid_simd::parse_batch(src.as_bytes(), &offsets[..5], &mut dst, &mut failures).is_err());
+
+    // pairs of valid and invalid rows of mixed forms, in odd and even counts
+    let rows: Vec<&str> = ok_cases()
+        .iter()
+        .map(|&(_, input)| input)
+        .chain(err_cases().iter().copied())
+        .collect();
+    for n in 0..rows.len() {
+        let rows = &rows[rows.len() - n..];
+        let src = rows.concat();
+        let mut offsets = vec![0];
+        for row in rows {
+            offsets.push(offsets.last().unwrap() + row.len());
+        }
+
+        let mut dst = vec![[0xff; 16]; n];
+        let mut failures = vec![0; n.div_ceil(64)];
+        let count = uuid_simd::parse_batch(src.as_bytes(), &offsets, &mut dst, &mut failures).unwrap();
+
+        let mut expected_count = 0;
+        for (i, row) in rows.iter().enumerate() {
+            let mut buf = [0; 16];
+            let failed = failures[i / 64] & (1 << (i % 64)) != 0;
+            match uuid_simd::parse(row.as_bytes(), buf.as_out()) {
+                Ok(uuid) => {
+                    assert!(!failed, "row = {row}");
+                    assert_eq!(dst[i], *uuid, "row = {row}");
+                }
+                Err(_) => {
+                    assert!(failed, "row = {row}");
+                    assert_eq!(dst[i], [0; 16]);
+                    expected_count += 1;
+                }
+            }
+        }
+        assert_eq!(count, expected_count);
+    }
+
+    // reversed offsets
+    let mut dst = [[0xff; 16]; 1];
+    let count = uuid_simd::parse_batch(b"", &[1, 0], &mut dst, &mut failures).unwrap();
+    assert_eq!(count, 1);
+
+    for n in [1, 3] {
+        let mut buf = vec![0; n * 36];
+        let formatted =
+            uuid_simd::format_hyphenated_batch(&expected[..n], buf.as_mut_slice().as_out(), AsciiCase::Lower).unwrap();
+        assert_eq!(
+            formatted,
+            &text.split(|&c| c == b'\n').take(n).collect::<Vec<_>>().concat()
+        );
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn fields() {
+    use uuid_simd::{Timestamp, ValidationError, Variant, VersionSet};
+
+    // RFC 9562, Appendix A
+    let cases: &[(&str, u8, Option<Timestamp>)] = &[
+        (
+            "C232AB00-9414-11EC-B3C8-9F6BDECED846",
+            1,
+            Some(Timestamp::Gregorian(0x1EC_9414_C232_AB00)),
+        ),
+        ("5df41881-3aed-3515-88a7-2f4a814cf09e", 3, None),
+        ("919108f7-52d1-4320-9bac-f847db4148a8", 4, None),
+        (
+            "1EC9414C-232A-6B00-B3C8-9F6BDECED846",
+            6,
+            Some(Timestamp::Gregorian(0x1EC_9414_C232_AB00)),
+        ),
+        (
+            "017F22E2-79B0-7CC3-98C4-DC0C0C07398F",
+            7,
+            Some(Timestamp::UnixMillis(0x017F_22E2_79B0)),
+        ),
+    ];
+
+    for &(input, version, timestamp) in cases {
+        let mut buf = [0; 16];
+        let uuid = uuid_simd::parse(input.as_bytes(), buf.as_out()).unwrap();
+        assert_eq!(uuid_simd::version(uuid), version);
+        assert_eq!(uuid_simd::variant(uuid), Variant::Rfc9562);
+        assert_eq!(uuid_simd::timestamp(uuid), timestamp);
+    }
+
+    let ts = Timestamp::Gregorian(0x1EC_9414_C232_AB00);
+    assert_eq!(ts.to_unix_millis(), Some(1_645_557_742_000));
+    assert_eq!(Timestamp::Gregorian(0).to_unix_millis(), None);
+
+    let v4_or_v7 = VersionSet::new(&[4, 7]);
+    assert!(v4_or_v7.contains(4) && v4_or_v7.contains(7) && !v4_or_v7.contains(1));
+
+    let mut buf = [0; 16];
+    let parse =
+        |s: &str, buf: &mut [u8; 16]| uuid_simd::parse_validated(s.as_bytes(), buf.as_out(), v4_or_v7).map(|_| ());
+    assert_eq!(parse("919108f7-52d1-4320-9bac-f847db4148a8", &mut buf), Ok(()));
+    assert_eq!(parse("017F22E2-79B0-7CC3-98C4-DC0C0C07398F", &mut buf), Ok(()));
+    assert_eq!(
+        parse("C232AB00-9414-11EC-B3C8-9F6BDECED846", &mut buf),
+        Err(ValidationError::Version(1))
+    );
+    assert_eq!(
+        parse("00000000-0000-0000-0000-000000000000", &mut buf),
+        Err(ValidationError::Variant(Variant::Ncs))
+    );
+    assert_eq!(
+        parse("919108f7-52d1-4320-cbac-f847db4148a8", &mut buf),
+        Err(ValidationError::Variant(Variant::Microsoft))
+    );
+    assert_eq!(
+        parse("ffffffff-ffff-ffff-ffff-ffffffffffff", &mut buf),
+        Err(ValidationError::Variant(Variant::Future))
+    );
+    assert_eq!(
+        parse("919108f7-52d1-4320-9bac-f847db4148a", &mut buf),
+        Err(ValidationError::Format)
+    );
+
+    assert!(uuid_simd::validate(&buf, VersionSet::ALL).is_err());
+    let uuid = uuid_simd::parse(b"5df41881-3aed-3515-88a7-2f4a814cf09e", buf.as_out()).unwrap();
+    assert!(uuid_simd::validate(uuid, VersionSet::ALL).is_ok());
+    assert_eq!(uuid_simd::validate(uuid, v4_or_v7), Err(ValidationError::Version(3)));
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn generate() {
+    use uuid_simd::{RandomSource, V7Generator, Variant};
+
+    struct XorShift(u64);
+
+    impl RandomSource for XorShift {
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            for b in dst {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                *b = self.0 as u8;
+            }
+        }
+    }
+
+    let mut rng1 = XorShift(42);
+    let mut rng2 = XorShift(42);
+
+    for _ in 0..100 {
+        let uuid = uuid_simd::new_v4(&mut rng1);
+        assert_eq!(uuid_simd::version(&uuid), 4);
+        assert_eq!(uuid_simd::variant(&uuid), Variant::Rfc9562);
+
+        let mut buf = [0; 36];
+        let text = uuid_simd::format_v4(&mut rng2, buf.as_out(), AsciiCase::Lower);
+        let mut parsed = [0; 16];
+        assert_eq!(uuid_simd::parse_hyphenated(text, parsed.as_out()).unwrap(), &uuid);
+    }
+
+    let mut gen1 = V7Generator::new();
+    let mut gen2 = V7Generator::new();
+    let ms = 0x017F_22E2_79B0;
+
+    let mut prev = [0; 16];
+    for i in 0..9000 {
+        // the clock goes backwards at i = 4000
+        let now = if i < 4000 { ms + i / 1000 } else { ms };
+        let uuid = gen1.generate(now, &mut rng1);
+        assert_eq!(uuid_simd::version(&uuid), 7);
+        assert_eq!(uuid_simd::variant(&uuid), Variant::Rfc9562);
+        assert!(uuid > prev, "i = {i}");
+        prev = uuid;
+
+        let mut buf = [0; 36];
+        let text = gen2.format(now, &mut rng2, buf.as_out(), AsciiCase::Upper);
+        let mut parsed = [0; 16];
+        assert_eq!(uuid_simd::parse_hyphenated(text, parsed.as_out()).unwrap(), &uuid);
+    }
+
+    let uuid = gen1.generate(ms + 10, &mut rng1);
+    assert_eq!(
+        uuid_simd::timestamp(&uuid),
+        Some(uuid_simd::Timestamp::UnixMillis(ms + 10))
+    );
+
+    struct Fill(u8);
+
+    impl RandomSource for Fill {
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            dst.fill(self.0);
+        }
+    }
+
+    // the first counter is a random seed even at the Unix epoch
+    let mut generator = V7Generator::new();
+    let first = generator.generate(0, &mut Fill(0xab));
+    assert_eq!(first[..8], [0, 0, 0, 0, 0, 0, 0x73, 0xab]);
+    let second = generator.generate(0, &mut Fill(0xab));
+    assert_eq!(second[..8], [0, 0, 0, 0, 0, 0, 0x73, 0xac]);
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn guid_le() {
+    // EFI System Partition
+    let text = "C12A7328-F81F-11D2-BA4B-00A0C93EC93B";
+    let expected: [u8; 16] = [
+        0x28, 0x73, 0x2A, 0xC1, 0x1F, 0xF8, 0xD2, 0x11, //
+        0xBA, 0x4B, 0x00, 0xA0, 0xC9, 0x3E, 0xC9, 0x3B, //
+    ];
+
+    let inputs = [
+        text.to_owned(),
+        text.replace('-', ""),
+        format!("{{{text}}}"),
+        format!("urn:uuid:{text}"),
+    ];
+    for input in &inputs {
+        let mut buf = [0; 16];
+        let ans = uuid_simd::parse_guid_le(input.as_bytes(), buf.as_out()).unwrap();
+        assert_eq!(*ans, expected, "input = {input}");
+    }
+
+    let mut buf = [0; 36];
+    let ans = uuid_simd::format_guid_le(&expected, buf.as_out(), AsciiCase::Upper);
+    assert_eq!(ans.as_slice(), text.as_bytes());
+
+    for &(_, input) in ok_cases() {
+        let mut be = [0; 16];
+        let mut le = [0; 16];
+        let be = uuid_simd::parse(input.as_bytes(), be.as_out()).unwrap();
+        let le = uuid_simd::parse_guid_le(input.as_bytes(), le.as_out()).unwrap();
+        assert_eq!(le[..4], [be[3], be[2], be[1], be[0]]);
+        assert_eq!(le[4..8], [be[5], be[4], be[7], be[6]]);
+        assert_eq!(le[8..], be[8..]);
+
+        let mut buf1 = [0; 36];
+        let mut buf2 = [0; 36];
+        let ans1 = uuid_simd::format_guid_le(le, buf1.as_out(), AsciiCase::Lower);
+        let ans2 = uuid_simd::format_hyphenated(be, buf2.as_out(), AsciiCase::Lower);
+        assert_eq!(ans1, ans2);
+    }
+
+    for &input in err_cases() {
+        let mut buf = [0; 16];
+        uuid_simd::parse_guid_le(input.as_bytes(), buf.as_out()).unwrap_err();
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn find_iter() {
+    fn naive(haystack: &[u8]) -> Vec<(usize, [u8; 16])> {
+        let mut ans = Vec::new();
+        let mut i = 0;
+        while i + 36 <= haystack.len() {
+            let mut buf = [0; 16];
+            if let Ok(uuid) = uuid_simd::parse_hyphenated(&haystack[i..i + 36], buf.as_out()) {
+                ans.push((i, *uuid));
+                i += 36;
+            } else {
+                i += 1;
+            }
+        }
+        ans
+    }
+
+    let text = concat!(
+        "GET /users/67e55044-10b1-426f-9247-bb680e5fe0c8/orders?id=F9168C5E-CEB2-4faa-B6BF-329BF39FA1E4 200\n",
+        "zzzzzzzz-zzzz-zzzz-zzzz-zzzzzzzzzzzz 01020304-1112-2122-3132-41424344454",
+        "x00000000-0000-0000-0000-00000000000001020304-1112-2122-3132-414243444546",
+    );
+    let found: Vec<usize> = uuid_simd::find_iter(text.as_bytes())
+        .map(|(offset, _)| offset)
+        .collect();
+    assert_eq!(found, [11, 58, 172, 208]);
+    assert_eq!(
+        uuid_simd::find_iter(text.as_bytes()).collect::<Vec<_>>(),
+        naive(text.as_bytes())
+    );
+
+    let uuid = "6d93bade-bd9f-4e13-8914-9474e1e3567b";
+    for n in 0..80 {
+        for offset in 0..=n {
+            let mut haystack = vec![b'-'; n];
+            haystack.splice(offset..offset, uuid.bytes());
+            let ans: Vec<_> = uuid_simd::find_iter(&haystack).collect();
+            assert_eq!(ans, naive(&haystack), "n = {n}, offset = {offset}");
+            assert_eq!(ans.len(), 1);
+            assert_eq!(ans[0].0, offset);
+        }
+    }
+
+    // the haystack is a prefix of a larger buffer, so a match must not run past its end
+    for prefix in 0..80 {
+        let mut buf = vec![b'x'; prefix];
+        buf.extend_from_slice(uuid.as_bytes());
+        buf.extend_from_slice(uuid.as_bytes());
+
+        let end = prefix + 36;
+        let ans: Vec<_> = uuid_simd::find_iter(&buf[..end]).collect();
+        assert_eq!(ans, naive(&buf[..end]), "prefix = {prefix}");
+        assert_eq!(ans.len(), 1);
+        assert_eq!(ans[0].0, prefix);
+
+        for t in 1..12 {
+            let haystack = &buf[..prefix + 24 + t];
+            let ans: Vec<_> = uuid_simd::find_iter(haystack).collect();
+            assert!(ans.is_empty(), "prefix = {prefix}, t = {t}");
+
+            let haystack = &buf[..end + 24 + t];
+            let ans: Vec<_> = uuid_simd::find_iter(haystack).collect();
+            assert_eq!(ans, naive(haystack), "prefix = {prefix}, t = {t}");
+            assert_eq!(ans.len(), 1);
+        }
+    }
+
+    let mut iter = uuid_simd::find_iter(b"");
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn compact() {
+    let cases: &[(&str, &str, &str, &str)] = &[
+        (
+            "00000000-0000-0000-0000-000000000000",
+            "AAAAAAAAAAAAAAAAAAAAAA",
+            "00000000000000000000000000",
+            "1111111111111111111111",
+        ),
+        (
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "_____________________w",
+            "7ZZZZZZZZZZZZZZZZZZZZZZZZZ",
+            "YcVfxkQb6JRzqk5kF2tNLv",
+        ),
+        (
+            "017F22E2-79B0-7CC3-98C4-DC0C0C07398F",
+            "AX8i4nmwfMOYxNwMDAc5jw",
+            "01FWHE4YDGFK1SHH6W1G60EECF",
+            "1BihbxwwQ4NZZpKRH9JDCz",
+        ),
+    ];
+
+    for &(text, base64url, crockford, base58) in cases {
+        let mut uuid = [0; 16];
+        let uuid = *uuid_simd::parse_hyphenated(text.as_bytes(), uuid.as_out()).unwrap();
+
+        let mut buf = [0; 22];
+        assert_eq!(uuid_simd::format_base64url(&uuid, buf.as_out()), base64url.as_bytes());
+        let mut buf = [0; 26];
+        assert_eq!(
+            uuid_simd::format_crockford(&uuid, buf.as_out(), AsciiCase::Upper),
+            crockford.as_bytes()
+        );
+        let ans = uuid_simd::format_crockford(&uuid, buf.as_out(), AsciiCase::Lower);
+        assert_eq!(ans, crockford.to_ascii_lowercase().as_bytes());
+
+        let mut buf = [0; 16];
+        assert_eq!(
+            uuid_simd::parse_base64url(base64url.as_bytes(), buf.as_out()).unwrap(),
+            &uuid
+        );
+        assert_eq!(
+            uuid_simd::parse_crockford(crockford.as_bytes(), buf.as_out()).unwrap(),
+            &uuid
+        );
+        let lower = crockford.to_ascii_lowercase();
+        assert_eq!(
+            uuid_simd::parse_crockford(lower.as_bytes(), buf.as_out()).unwrap(),
+            &uuid
+        );
+
+        assert_eq!(uuid_simd::parse_base58(base58.as_bytes(), buf.as_out()).unwrap(), &uuid);
+        let mut buf = [0; 22];
+        assert_eq!(uuid_simd::format_base58(&uuid, buf.as_out()), base58.as_bytes());
+    }
+
+    let mut buf = [0; 16];
+    let ans = uuid_simd::parse_crockford(b"0IL0o000000000000000000000", buf.as_out()).unwrap();
+    assert_eq!(ans[..3], [0x01, 0x08, 0x00]);
+
+    let base64url_err = [
+        "",
+        "AAAAAAAAAAAAAAAAAAAAA",
+        "AAAAAAAAAAAAAAAAAAAAAB",
+        "AAAAAAAAAAAAAAAAAAAA+A",
+        "AAAAAAAAAAAAAAAAAAAAAA=",
+    ];
+    for input in base64url_err {
+        assert!(
+            uuid_simd::parse_base64url(input.as_bytes(), buf.as_out()).is_err(),
+            "{input}"
+        );
+    }
+    let crockford_err = [
+        "",
+        "80000000000000000000000000",
+        "0000000000000000000000000U",
+        "000000000000000000000000000",
+    ];
+    for input in crockford_err {
+        assert!(
+            uuid_simd::parse_crockford(input.as_bytes(), buf.as_out()).is_err(),
+            "{input}"
+        );
+    }
+
+    let base58_err = [
+        "",
+        "111111111111111111111",
+        "11111111111111111111110",
+        "111111111111111111111l",
+        "YcVfxkQb6JRzqk5kF2tNLw",
+        "zzzzzzzzzzzzzzzzzzzzzz",
+    ];
+    for input in base58_err {
+        assert!(
+            uuid_simd::parse_base58(input.as_bytes(), buf.as_out()).is_err(),
+            "{input}"
+        );
     }
+
+    // an invalid character at each position
+    for i in 0..22 {
+        let mut input = *b"AX8i4nmwfMOYxNwMDAc5jw";
+        input[i] = b'=';
+        assert!(uuid_simd::parse_base64url(&input, buf.as_out()).is_err(), "i = {i}");
+    }
+    for i in 0..26 {
+        let mut input = *b"01FWHE4YDGFK1SHH6W1G60EECF";
+        input[i] = b'U';
+        assert!(uuid_simd::parse_crockford(&input, buf.as_out()).is_err(), "i = {i}");
+    }
+
+    let mut uuid = [0u8; 16];
+    for i in 0..=255u8 {
+        uuid = uuid.map(|x| x.wrapping_mul(31).wrapping_add(i));
+        let mut buf = [0; 22];
+        let ans = uuid_simd::format_base64url(&uuid, buf.as_out());
+        assert_eq!(
+            ans.as_slice(),
+            base64_simd::URL_SAFE_NO_PAD.encode_to_string(uuid).as_bytes()
+        );
+        let mut out = [0; 16];
+        assert_eq!(uuid_simd::parse_base64url(ans, out.as_out()).unwrap(), &uuid);
+
+        let mut buf = [0; 26];
+        let ans = uuid_simd::format_crockford(&uuid, buf.as_out(), AsciiCase::Upper);
+        let x = u128::from_be_bytes(uuid);
+        for (j, &c) in ans.iter().enumerate() {
+            let digit = (x >> (125 - j * 5)) & 0x1f;
+            assert_eq!(c, b"0123456789ABCDEFGHJKMNPQRSTVWXYZ"[digit as usize]);
+        }
+        assert_eq!(uuid_simd::parse_crockford(ans, out.as_out()).unwrap(), &uuid);
+
+        let mut buf = [0; 22];
+        let ans = uuid_simd::format_base58(&uuid, buf.as_out());
+        assert_eq!(uuid_simd::parse_base58(ans, out.as_out()).unwrap(), &uuid);
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn ulid() {
+    use uuid_simd::{ulid, RandomSource};
+
+    struct Counter(u8);
+
+    impl RandomSource for Counter {
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            for b in dst {
+                self.0 = self.0.wrapping_add(1);
+                *b = self.0;
+            }
+        }
+    }
+
+    let text = "01ARZ3NDEKTSV4RRFFQ69G5FAV";
+    let mut buf = [0; 16];
+    let bytes = *ulid::parse(text.as_bytes(), buf.as_out()).unwrap();
+    assert_eq!(ulid::timestamp(&bytes), 1_469_922_850_259);
+
+    let mut buf = [0; 26];
+    assert_eq!(ulid::format(&bytes, buf.as_out(), AsciiCase::Upper), text.as_bytes());
+
+    let uuid = ulid::to_uuid_v7(&bytes);
+    assert_eq!(uuid_simd::version(&uuid), 7);
+    assert_eq!(uuid_simd::variant(&uuid), uuid_simd::Variant::Rfc9562);
+    assert_eq!(
+        uuid_simd::timestamp(&uuid),
+        Some(uuid_simd::Timestamp::UnixMillis(1_469_922_850_259))
+    );
+    assert_eq!(ulid::timestamp(&ulid::from_uuid(&uuid)), 1_469_922_850_259);
+
+    let mut generator = ulid::Generator::new();
+    let mut rng = Counter(0);
+    let ms = 1_469_922_850_259;
+
+    let first = generator.generate(ms, &mut rng).unwrap();
+    assert_eq!(ulid::timestamp(&first), ms);
+    assert_eq!(first[6..], [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+
+    let mut prev = first;
+    for now in [ms, ms, ms + 1, ms + 1] {
+        let next = generator.generate(now, &mut rng).unwrap();
+        assert!(next > prev);
+        prev = next;
+    }
+    assert_eq!(ulid::timestamp(&prev), ms + 1);
+
+    // the clock goes backwards
+    assert!(generator.generate(ms, &mut rng).is_err());
+    let next = generator.generate(ms + 1, &mut rng).unwrap();
+    assert!(next > prev);
+
+    let mut buf = [0; 26];
+    let text = generator
+        .format(ms + 2, &mut rng, buf.as_out(), AsciiCase::Lower)
+        .unwrap();
+    let mut parsed = [0; 16];
+    assert_eq!(ulid::timestamp(ulid::parse(text, parsed.as_out()).unwrap()), ms + 2);
+
+    struct Max;
+
+    impl RandomSource for Max {
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            dst.fill(0xff);
+        }
+    }
+
+    struct NearMax;
+
+    impl RandomSource for NearMax {
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            dst.fill(0xff);
+            dst[dst.len() - 1] = 0xfe;
+        }
+    }
+
+    let mut generator = ulid::Generator::new();
+    assert!(generator.generate(ms, &mut Max).is_ok());
+    assert!(generator.generate(ms, &mut Max).is_err());
+    assert!(generator.generate(1 << 48, &mut Max).is_err());
+
+    // the random part reaches RANDOM_MAX, then overflows until the next millisecond
+    let mut generator = ulid::Generator::new();
+    let a = generator.generate(ms, &mut NearMax).unwrap();
+    assert_eq!(a[6..], [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe]);
+    let b = generator.generate(ms, &mut NearMax).unwrap();
+    assert_eq!(b[6..], [0xff; 10]);
+    assert_eq!(ulid::timestamp(&b), ms);
+    assert!(generator.generate(ms, &mut NearMax).is_err());
+    assert!(generator.generate(ms, &mut NearMax).is_err());
+    let c = generator.generate(ms + 1, &mut NearMax).unwrap();
+    assert_eq!(ulid::timestamp(&c), ms + 1);
+
+    // the first ULID draws randomness even at the Unix epoch
+    let mut generator = ulid::Generator::new();
+    let first = generator.generate(0, &mut Max).unwrap();
+    assert_eq!(
+        first,
+        [0, 0, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    );
+    assert!(generator.generate(0, &mut Max).is_err());
+    assert_eq!(
+        generator.generate(1, &mut Counter(0)).unwrap()[6..],
+        [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]
+    );
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn utf16() {
+    for &(_, input) in ok_cases() {
+        let units: Vec<u16> = input.encode_utf16().collect();
+        let mut expected = [0; 16];
+        let expected = uuid_simd::parse(input.as_bytes(), expected.as_out()).unwrap();
+        let mut buf = [0; 16];
+        assert_eq!(
+            uuid_simd::parse_utf16(&units, buf.as_out()).unwrap(),
+            expected,
+            "input = {input}"
+        );
+
+        for case in [AsciiCase::Lower, AsciiCase::Upper] {
+            let mut buf1 = [0; 36];
+            let mut buf2 = [0; 36];
+            let ans1 = uuid_simd::format_hyphenated_utf16(expected, buf1.as_out(), case);
+            let ans2 = uuid_simd::format_hyphenated(expected, buf2.as_out(), case);
+            assert_eq!(String::from_utf16(ans1).unwrap().as_bytes(), ans2);
+        }
+    }
+
+    for &input in err_cases() {
+        let units: Vec<u16> = input.encode_utf16().collect();
+        let mut buf = [0; 16];
+        uuid_simd::parse_utf16(&units, buf.as_out()).unwrap_err();
+    }
+
+    // non-ascii code units whose low bytes are valid
+    let text = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    for i in 0..text.len() {
+        for high in [0x0100, 0x3000, 0xff00] {
+            let mut units: Vec<u16> = text.encode_utf16().collect();
+            units[i] |= high;
+            let mut buf = [0; 16];
+            assert!(uuid_simd::parse_utf16(&units, buf.as_out()).is_err(), "i = {i}");
+
+            let mut units: Vec<u16> = text.replace('-', "").encode_utf16().collect();
+            if i < 32 {
+                units[i] |= high;
+                assert!(uuid_simd::parse_utf16(&units, buf.as_out()).is_err(), "i = {i}");
+            }
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+fn parse_options() {
+    use uuid_simd::ParseOptions;
+
+    let default = ParseOptions::new();
+    for &(_, input) in ok_cases() {
+        let mut buf1 = [0; 16];
+        let mut buf2 = [0; 16];
+        let ans1 = default.parse(input.as_bytes(), buf1.as_out()).unwrap();
+        let ans2 = uuid_simd::parse(input.as_bytes(), buf2.as_out()).unwrap();
+        assert_eq!(ans1, ans2);
+    }
+    for &input in err_cases() {
+        let mut buf = [0; 16];
+        default.parse(input.as_bytes(), buf.as_out()).unwrap_err();
+    }
+
+    let simple = "67e5504410b1426f9247bb680e5fe0c8";
+    let hyphenated = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+    let braced = "{67e55044-10b1-426f-9247-bb680e5fe0c8}";
+    let urn = "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8";
+    let urn_upper = "URN:UUID:67e55044-10b1-426f-9247-bb680e5fe0c8";
+    let upper = "67E55044-10B1-426F-9247-BB680E5FE0C8";
+
+    let cases: &[(ParseOptions, [bool; 6])] = &[
+        (default, [true, true, true, true, false, true]),
+        (default.simple(false), [false, true, true, true, false, true]),
+        (default.hyphenated(false), [true, false, true, true, false, false]),
+        (
+            default.braced(false).urn(false),
+            [true, true, false, false, false, true],
+        ),
+        (default.urn_ignore_case(true), [true, true, true, true, true, true]),
+        (default.lowercase(true), [true, true, true, true, false, false]),
+        (
+            default
+                .simple(false)
+                .braced(false)
+                .urn(false)
+                .urn(true)
+                .urn_ignore_case(true)
+                .lowercase(true),
+            [false, true, false, true, true, false],
+        ),
+    ];
+
+    for (options, expected) in cases {
+        let inputs = [simple, hyphenated, braced, urn, urn_upper, upper];
+        for (input, &ok) in inputs.iter().zip(expected) {
+            let mut buf = [0; 16];
+            let ans = options.parse(input.as_bytes(), buf.as_out());
+            assert_eq!(ans.is_ok(), ok, "options = {options:?}, input = {input}");
+        }
+    }
+
+    // denied forms and malformed wrappers
+    let denied: &[(ParseOptions, &str)] = &[
+        (default.simple(false), simple),
+        (default.hyphenated(false), hyphenated),
+        (default.braced(false), braced),
+        (default.urn(false), urn),
+        (default.urn(false).urn_ignore_case(true), urn_upper),
+        (default, "(67e55044-10b1-426f-9247-bb680e5fe0c8)"),
+        (default, "{67e55044-10b1-426f-9247-bb680e5fe0c8)"),
+        (
+            default.urn_ignore_case(true),
+            "urn:uuid-67e55044-10b1-426f-9247-bb680e5fe0c8",
+        ),
+        (
+            default.urn_ignore_case(true),
+            "urm:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+        ),
+    ];
+    for &(options, input) in denied {
+        let mut buf = [0; 16];
+        let ans = options.parse(input.as_bytes(), buf.as_out());
+        assert!(ans.is_err(), "options = {options:?}, input = {input}");
+    }
+
+    // every case mix of the URN prefix
+    let ignore_case = default.urn_ignore_case(true);
+    for mask in 0..(1u32 << 7) {
+        let mut input = urn.as_bytes().to_vec();
+        let letters = [0, 1, 2, 4, 5, 6, 7];
+        for (bit, &i) in letters.iter().enumerate() {
+            if mask & (1 << bit) != 0 {
+                input[i] = input[i].to_ascii_uppercase();
+            }
+        }
+        let mut buf = [0; 16];
+        assert!(ignore_case.parse(&input, buf.as_out()).is_ok());
+        assert_eq!(default.parse(&input, buf.as_out()).is_ok(), mask == 0);
+    }
+
+    // an uppercase digit at every position is rejected by `lowercase`
+    let lowercase = default.lowercase(true);
+    for input in [simple, hyphenated, braced, urn] {
+        let mut expected = [0; 16];
+        let expected = *default.parse(input.as_bytes(), expected.as_out()).unwrap();
+        let mut buf = [0; 16];
+        assert_eq!(*lowercase.parse(input.as_bytes(), buf.as_out()).unwrap(), expected);
+
+        for i in 0..input.len() {
+            let mut bytes = input.as_bytes().to_vec();
+            if !matches!(bytes[i], b'a'..=b'f') || (input == urn && i < 9) {
+                continue;
+            }
+            bytes[i] = bytes[i].to_ascii_uppercase();
+            let mut buf = [0; 16];
+            assert!(default.parse(&bytes, buf.as_out()).is_ok());
+            assert!(lowercase.parse(&bytes, buf.as_out()).is_err(), "input = {bytes:?}");
+        }
+    }
+
+    assert_eq!(ParseOptions::default(), ParseOptions::new());
 }
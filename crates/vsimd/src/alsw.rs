@@ -125,6 +125,207 @@ fn u8xn_avgr<S: Scalable<V>, V: POD>(s: S, a: V, b: V) -> V {
     s.u8xn_avgr(a, b)
 }
 
+/// The maximum number of steps of each hash search.
+const SEARCH_STEPS: usize = 1024;
+
+/// Searches ALSW tables for an alphabet.
+///
+/// `table` maps characters to values, or `0xff` for invalid characters.
+/// Returns `None` if the alphabet is not compatible with ALSW
+/// or the tables can not be found in a bounded number of steps.
+#[inline]
+#[must_use]
+pub const fn search(table: &[u8; 256]) -> Option<(AlswLut<V128>, AlswLut<V128>)> {
+    let mut c = 0x80;
+    while c < 256 {
+        if table[c] != 0xff {
+            return None;
+        }
+        c += 1;
+    }
+
+    let Some(check_hash) = search_check_hash(table) else {
+        return None;
+    };
+    let Some(decode_hash) = search_decode_hash(table) else {
+        return None;
+    };
+
+    let mut check_offset = [0x80; 16];
+    let mut decode_offset = [0x80; 16];
+    let mut c: u8 = 255;
+    loop {
+        let idx = table[c as usize];
+        if idx != 0xff {
+            check_offset[(hash(&check_hash, c) & 0x0f) as usize] = 0u8.wrapping_sub(c);
+            decode_offset[(hash(&decode_hash, c) & 0x0f) as usize] = idx.wrapping_sub(c);
+        }
+        if c == 0 {
+            break;
+        }
+        c -= 1;
+    }
+
+    let mut c: u8 = 0;
+    loop {
+        let idx = table[c as usize];
+        if (check(&check_hash, &check_offset, c) < 0x80) != (idx != 0xff) {
+            return None;
+        }
+        if idx != 0xff && decode(&decode_hash, &decode_offset, c) != idx {
+            return None;
+        }
+        if c == 255 {
+            break;
+        }
+        c += 1;
+    }
+
+    let check_lut = AlswLut {
+        hash: V128::from_bytes(check_hash),
+        offset: V128::from_bytes(check_offset),
+    };
+    let decode_lut = AlswLut {
+        hash: V128::from_bytes(decode_hash),
+        offset: V128::from_bytes(decode_offset),
+    };
+    Some((check_lut, decode_lut))
+}
+
+/// Converts hash choices to a hash lut.
+///
+/// A choice `x` moves the characters `c` with the low nibble `i` to the bucket `x + (c >> 4)`.
+const fn hash_lut(xs: &[u8; 16]) -> [u8; 16] {
+    let mut arr = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        arr[i] = (xs[i] << 1) - 1;
+        i += 1;
+    }
+    arr
+}
+
+/// Each bucket accepts the characters in `[min_valid, 0x80)`.
+const fn search_check_hash(table: &[u8; 256]) -> Option<[u8; 16]> {
+    let mut xs = [0u8; 16];
+    let mut min_valid = [[0x100u16; 16]; 17];
+    let mut max_invalid = [[0u16; 16]; 17]; // exclusive
+
+    let mut depth = 0;
+    let mut steps = 0;
+    loop {
+        xs[depth] += 1;
+        if xs[depth] >= 16 {
+            if depth == 0 {
+                return None;
+            }
+            xs[depth] = 0;
+            depth -= 1;
+            continue;
+        }
+
+        steps += 1;
+        if steps > SEARCH_STEPS {
+            return None;
+        }
+
+        let x = xs[depth] as usize;
+        let mut lo = min_valid[depth];
+        let mut hi = max_invalid[depth];
+        let mut ok = true;
+        let mut row = 0;
+        while row < 8 {
+            let c = row * 16 + depth;
+            let is_valid = table[c] != 0xff;
+            let bucket = x + row;
+            if bucket >= 16 {
+                // the offset is zero
+                if !is_valid {
+                    ok = false;
+                    break;
+                }
+            } else {
+                if is_valid {
+                    if (c as u16) < lo[bucket] {
+                        lo[bucket] = c as u16;
+                    }
+                } else if (c as u16) + 1 > hi[bucket] {
+                    hi[bucket] = (c as u16) + 1;
+                }
+                if hi[bucket] > lo[bucket] {
+                    ok = false;
+                    break;
+                }
+            }
+            row += 1;
+        }
+
+        if ok {
+            depth += 1;
+            if depth == 16 {
+                return Some(hash_lut(&xs));
+            }
+            min_valid[depth] = lo;
+            max_invalid[depth] = hi;
+            xs[depth] = 0;
+        }
+    }
+}
+
+/// Each bucket adds the same offset to its characters.
+const fn search_decode_hash(table: &[u8; 256]) -> Option<[u8; 16]> {
+    let mut xs = [0u8; 16];
+    let mut offsets = [[0x100u16; 16]; 17];
+
+    let mut depth = 0;
+    let mut steps = 0;
+    loop {
+        xs[depth] += 1;
+        if xs[depth] >= 16 {
+            if depth == 0 {
+                return None;
+            }
+            xs[depth] = 0;
+            depth -= 1;
+            continue;
+        }
+
+        steps += 1;
+        if steps > SEARCH_STEPS {
+            return None;
+        }
+
+        let x = xs[depth] as usize;
+        let mut offset = offsets[depth];
+        let mut ok = true;
+        let mut row = 0;
+        while row < 8 {
+            let c = row * 16 + depth;
+            let idx = table[c];
+            row += 1;
+            if idx == 0xff {
+                continue;
+            }
+            let bucket = x + row - 1;
+            let delta = idx.wrapping_sub(c as u8) as u16;
+            if bucket >= 16 || (offset[bucket] != 0x100 && offset[bucket] != delta) {
+                ok = false;
+                break;
+            }
+            offset[bucket] = delta;
+        }
+
+        if ok {
+            depth += 1;
+            if depth == 16 {
+                return Some(hash_lut(&xs));
+            }
+            offsets[depth] = offset;
+            xs[depth] = 0;
+        }
+    }
+}
+
 #[macro_export]
 macro_rules! impl_alsw {
     ($spec:ty) => {
@@ -184,6 +385,7 @@ macro_rules! impl_alsw {
                 arr
             };
 
+            #[allow(dead_code)]
             #[inline]
             #[must_use]
             const fn check_lut() -> AlswLut<V128> {
@@ -193,6 +395,7 @@ macro_rules! impl_alsw {
                 }
             }
 
+            #[allow(dead_code)]
             #[inline]
             #[must_use]
             const fn decode_lut() -> AlswLut<V128> {
@@ -202,6 +405,7 @@ macro_rules! impl_alsw {
                 }
             }
 
+            #[allow(dead_code)]
             #[cfg(test)]
             fn test_check() {
                 let hash = &Self::CHECK_HASH;
@@ -214,6 +418,7 @@ macro_rules! impl_alsw {
                 }
             }
 
+            #[allow(dead_code)]
             #[cfg(test)]
             fn test_decode() {
                 let hash = &Self::DECODE_HASH;
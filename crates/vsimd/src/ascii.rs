@@ -29,6 +29,30 @@ pub fn to_ascii_uppercase<S: Scalable<V>, V: POD>(s: S, x: V) -> V {
     convert_ascii_case::<S, V, b'a'>(s, x)
 }
 
+/// Returns a mask of ascii whitespace bytes (TAB, LF, FF, CR, SPACE).
+#[inline(always)]
+pub fn ascii_whitespace_mask<S: Scalable<V>, V: POD>(s: S, x: V) -> V {
+    // ASCII whitespaces
+    // TAB      0x09    00001001
+    // LF       0x0a    00001010
+    // FF       0x0c    00001100
+    // CR       0x0d    00001101
+    // SPACE    0x20    00010000
+    //
+
+    // m1 = {{byte in 0x09..=0x0d}}
+    let m1 = s.i8xn_lt(s.u8xn_sub(x, s.u8xn_splat(0x89)), s.i8xn_splat(-128 + 5));
+
+    // m2 = {{byte == 0x0b}}
+    let m2 = s.u8xn_eq(x, s.u8xn_splat(0x0b));
+
+    // m3 = {{byte is SPACE}}
+    let m3 = s.u8xn_eq(x, s.u8xn_splat(0x20));
+
+    // (m1 & !m2) | m3
+    s.or(s.andnot(m1, m2), m3)
+}
+
 #[cfg(test)]
 mod algorithm {
     #[cfg(feature = "std")]
@@ -42,7 +66,7 @@ mod algorithm {
 
     #[cfg(feature = "std")]
     #[test]
-    #[ignore]
+    #[ignore = "prints debug tables"]
     fn convert_case() {
         let convert = |c: u8, shift: u8| {
             let x1 = c.wrapping_sub(shift + 0x80);
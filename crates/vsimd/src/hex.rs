@@ -1,4 +1,5 @@
 use crate::alsw::{self, AlswLut};
+use crate::ascii::AsciiCase;
 use crate::isa::{AVX2, NEON, SSSE3, WASM128};
 use crate::mask::{u8x16_highbit_any, u8x32_highbit_any};
 use crate::pod::POD;
@@ -32,6 +33,35 @@ pub const fn unhex(x: u8) -> u8 {
     UNHEX_TABLE[x as usize]
 }
 
+const fn parse_hex_case(x: u8, case: AsciiCase) -> u8 {
+    match (x, case) {
+        (b'0'..=b'9', _) => x - b'0',
+        (b'a'..=b'f', AsciiCase::Lower) => x - b'a' + 10,
+        (b'A'..=b'F', AsciiCase::Upper) => x - b'A' + 10,
+        _ => 0xff,
+    }
+}
+
+#[inline(always)]
+#[must_use]
+pub const fn unhex_case(x: u8, case: AsciiCase) -> u8 {
+    const fn table(case: AsciiCase) -> [u8; 256] {
+        let mut arr = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            arr[i] = parse_hex_case(i as u8, case);
+            i += 1;
+        }
+        arr
+    }
+    const LOWER_UNHEX_TABLE: &[u8; 256] = &table(AsciiCase::Lower);
+    const UPPER_UNHEX_TABLE: &[u8; 256] = &table(AsciiCase::Upper);
+    match case {
+        AsciiCase::Lower => LOWER_UNHEX_TABLE[x as usize],
+        AsciiCase::Upper => UPPER_UNHEX_TABLE[x as usize],
+    }
+}
+
 #[inline(always)]
 pub fn check_xn<S, V>(s: S, x: V) -> bool
 where
@@ -99,12 +129,84 @@ impl HexAlsw {
 
 impl_alsw!(HexAlsw);
 
+struct LowerHexAlsw;
+
+impl LowerHexAlsw {
+    const fn decode(c: u8) -> u8 {
+        parse_hex_case(c, AsciiCase::Lower)
+    }
+
+    const fn check_hash(i: u8) -> u8 {
+        HexAlsw::check_hash(i)
+    }
+
+    const fn decode_hash(i: u8) -> u8 {
+        HexAlsw::decode_hash(i)
+    }
+}
+
+impl_alsw!(LowerHexAlsw);
+
+struct UpperHexAlsw;
+
+impl UpperHexAlsw {
+    const fn decode(c: u8) -> u8 {
+        parse_hex_case(c, AsciiCase::Upper)
+    }
+
+    const fn check_hash(i: u8) -> u8 {
+        HexAlsw::check_hash(i)
+    }
+
+    const fn decode_hash(i: u8) -> u8 {
+        HexAlsw::decode_hash(i)
+    }
+}
+
+impl_alsw!(UpperHexAlsw);
+
 const HEX_ALSW_CHECK: AlswLut<V128> = HexAlsw::check_lut();
 const HEX_ALSW_DECODE: AlswLut<V128> = HexAlsw::decode_lut();
 
 const HEX_ALSW_CHECK_X2: AlswLut<V256> = HexAlsw::check_lut().x2();
 const HEX_ALSW_DECODE_X2: AlswLut<V256> = HexAlsw::decode_lut().x2();
 
+const LOWER_HEX_ALSW_CHECK: AlswLut<V128> = LowerHexAlsw::check_lut();
+const LOWER_HEX_ALSW_DECODE: AlswLut<V128> = LowerHexAlsw::decode_lut();
+const UPPER_HEX_ALSW_CHECK: AlswLut<V128> = UpperHexAlsw::check_lut();
+const UPPER_HEX_ALSW_DECODE: AlswLut<V128> = UpperHexAlsw::decode_lut();
+
+const LOWER_HEX_ALSW_CHECK_X2: AlswLut<V256> = LowerHexAlsw::check_lut().x2();
+const LOWER_HEX_ALSW_DECODE_X2: AlswLut<V256> = LowerHexAlsw::decode_lut().x2();
+const UPPER_HEX_ALSW_CHECK_X2: AlswLut<V256> = UpperHexAlsw::check_lut().x2();
+const UPPER_HEX_ALSW_DECODE_X2: AlswLut<V256> = UpperHexAlsw::decode_lut().x2();
+
+#[inline(always)]
+const fn case_lut16(case: AsciiCase) -> (AlswLut<V128>, AlswLut<V128>) {
+    match case {
+        AsciiCase::Lower => (LOWER_HEX_ALSW_CHECK, LOWER_HEX_ALSW_DECODE),
+        AsciiCase::Upper => (UPPER_HEX_ALSW_CHECK, UPPER_HEX_ALSW_DECODE),
+    }
+}
+
+#[inline(always)]
+const fn case_lut32(case: AsciiCase) -> (AlswLut<V256>, AlswLut<V256>) {
+    match case {
+        AsciiCase::Lower => (LOWER_HEX_ALSW_CHECK_X2, LOWER_HEX_ALSW_DECODE_X2),
+        AsciiCase::Upper => (UPPER_HEX_ALSW_CHECK_X2, UPPER_HEX_ALSW_DECODE_X2),
+    }
+}
+
+#[inline(always)]
+pub fn check_case16<S: SIMD128>(s: S, x: V128, case: AsciiCase) -> bool {
+    alsw::check_ascii_xn(s, x, case_lut16(case).0)
+}
+
+#[inline(always)]
+pub fn check_case32<S: SIMD256>(s: S, x: V256, case: AsciiCase) -> bool {
+    alsw::check_ascii_xn(s, x, case_lut32(case).0)
+}
+
 const DECODE_UZP1: V256 = V256::double_bytes([
     0x00, 0x02, 0x04, 0x06, 0x08, 0x0a, 0x0c, 0x0e, //
     0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, //
@@ -130,21 +232,32 @@ fn merge_bits<S: Scalable<V>, V: POD>(s: S, x: V) -> V {
 }
 
 #[inline(always)]
-fn decode16<S: SIMD128>(s: S, x: V128) -> (V128, V128) {
-    let (c1, c2) = alsw::decode_ascii_xn(s, x, HEX_ALSW_CHECK, HEX_ALSW_DECODE);
+fn decode16<S: SIMD128>(s: S, x: V128, lut: (AlswLut<V128>, AlswLut<V128>)) -> (V128, V128) {
+    let (c1, c2) = alsw::decode_ascii_xn(s, x, lut.0, lut.1);
     (merge_bits(s, c2), c1)
 }
 
 #[inline(always)]
-fn decode32<S: SIMD256>(s: S, x: V256) -> (V256, V256) {
-    let (c1, c2) = alsw::decode_ascii_xn(s, x, HEX_ALSW_CHECK_X2, HEX_ALSW_DECODE_X2);
+fn decode32<S: SIMD256>(s: S, x: V256, lut: (AlswLut<V256>, AlswLut<V256>)) -> (V256, V256) {
+    let (c1, c2) = alsw::decode_ascii_xn(s, x, lut.0, lut.1);
     (merge_bits(s, c2), c1)
 }
 
 #[allow(clippy::result_unit_err)]
 #[inline(always)]
 pub fn decode_ascii16<S: SIMD128>(s: S, x: V128) -> Result<V64, ()> {
-    let (y, is_invalid) = decode16(s, x);
+    decode_ascii16_with(s, x, (HEX_ALSW_CHECK, HEX_ALSW_DECODE))
+}
+
+#[allow(clippy::result_unit_err)]
+#[inline(always)]
+pub fn decode_case_ascii16<S: SIMD128>(s: S, x: V128, case: AsciiCase) -> Result<V64, ()> {
+    decode_ascii16_with(s, x, case_lut16(case))
+}
+
+#[inline(always)]
+fn decode_ascii16_with<S: SIMD128>(s: S, x: V128, lut: (AlswLut<V128>, AlswLut<V128>)) -> Result<V64, ()> {
+    let (y, is_invalid) = decode16(s, x, lut);
 
     let ans = if matches_isa!(S, SSSE3 | WASM128) {
         const UZP1: V128 = DECODE_UZP1.to_v128x2().0;
@@ -166,7 +279,18 @@ pub fn decode_ascii16<S: SIMD128>(s: S, x: V128) -> Result<V64, ()> {
 #[allow(clippy::result_unit_err)]
 #[inline(always)]
 pub fn decode_ascii32<S: SIMD256>(s: S, x: V256) -> Result<V128, ()> {
-    let (y, is_invalid) = decode32(s, x);
+    decode_ascii32_with(s, x, (HEX_ALSW_CHECK_X2, HEX_ALSW_DECODE_X2))
+}
+
+#[allow(clippy::result_unit_err)]
+#[inline(always)]
+pub fn decode_case_ascii32<S: SIMD256>(s: S, x: V256, case: AsciiCase) -> Result<V128, ()> {
+    decode_ascii32_with(s, x, case_lut32(case))
+}
+
+#[inline(always)]
+fn decode_ascii32_with<S: SIMD256>(s: S, x: V256, lut: (AlswLut<V256>, AlswLut<V256>)) -> Result<V128, ()> {
+    let (y, is_invalid) = decode32(s, x, lut);
 
     let ans = if matches_isa!(S, SSSE3 | WASM128) {
         let (a, b) = s.u8x16x2_swizzle(y, DECODE_UZP1).to_v128x2();
@@ -188,29 +312,45 @@ pub fn decode_ascii32<S: SIMD256>(s: S, x: V256) -> Result<V128, ()> {
 #[allow(clippy::result_unit_err)]
 #[inline(always)]
 pub fn decode_ascii32x2<S: SIMD256>(s: S, x: (V256, V256)) -> Result<V256, ()> {
-    let (y1, is_invalid1) = decode32(s, x.0);
-    let (y2, is_invalid2) = decode32(s, x.1);
+    decode_ascii32x2_with(s, x, (HEX_ALSW_CHECK_X2, HEX_ALSW_DECODE_X2))
+}
+
+#[allow(clippy::result_unit_err)]
+#[inline(always)]
+pub fn decode_case_ascii32x2<S: SIMD256>(s: S, x: (V256, V256), case: AsciiCase) -> Result<V256, ()> {
+    decode_ascii32x2_with(s, x, case_lut32(case))
+}
+
+#[inline(always)]
+fn decode_ascii32x2_with<S: SIMD256>(s: S, x: (V256, V256), lut: (AlswLut<V256>, AlswLut<V256>)) -> Result<V256, ()> {
+    let (y1, is_invalid1) = decode32(s, x.0, lut);
+    let (y2, is_invalid2) = decode32(s, x.1, lut);
     let is_invalid = s.v256_or(is_invalid1, is_invalid2);
 
-    let ans = if matches_isa!(S, AVX2) {
-        let ab = s.u8x16x2_swizzle(y1, DECODE_UZP1);
-        let cd = s.u8x16x2_swizzle(y2, DECODE_UZP2);
+    let ans = unzip_even_bytes(s, y1, y2);
+
+    if u8x32_highbit_any(s, is_invalid) {
+        Err(())
+    } else {
+        Ok(ans)
+    }
+}
+
+#[inline(always)]
+pub fn unzip_even_bytes<S: SIMD256>(s: S, a: V256, b: V256) -> V256 {
+    if matches_isa!(S, AVX2) {
+        let ab = s.u8x16x2_swizzle(a, DECODE_UZP1);
+        let cd = s.u8x16x2_swizzle(b, DECODE_UZP2);
         let acbd = s.v256_or(ab, cd);
         s.u64x4_permute::<0b_1101_1000>(acbd) // 0213
     } else if matches_isa!(S, SSSE3 | WASM128) {
-        let ab = s.u8x16x2_swizzle(y1, DECODE_UZP1);
-        let cd = s.u8x16x2_swizzle(y2, DECODE_UZP1);
+        let ab = s.u8x16x2_swizzle(a, DECODE_UZP1);
+        let cd = s.u8x16x2_swizzle(b, DECODE_UZP1);
         s.u64x4_unzip_even(ab, cd)
     } else if matches_isa!(S, NEON) {
-        s.u8x32_unzip_even(y1, y2)
+        s.u8x32_unzip_even(a, b)
     } else {
         unreachable!()
-    };
-
-    if u8x32_highbit_any(s, is_invalid) {
-        Err(())
-    } else {
-        Ok(ans)
     }
 }
 
@@ -318,4 +458,16 @@ mod algorithm {
         HexAlsw::test_check();
         HexAlsw::test_decode();
     }
+
+    #[test]
+    #[cfg_attr(
+        any(miri, not(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))),
+        ignore
+    )]
+    fn hex_case_alsw() {
+        LowerHexAlsw::test_check();
+        LowerHexAlsw::test_decode();
+        UpperHexAlsw::test_check();
+        UpperHexAlsw::test_decode();
+    }
 }
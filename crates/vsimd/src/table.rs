@@ -1,6 +1,9 @@
 use crate::isa::{NEON, SSSE3, WASM128};
 use crate::pod::POD;
-use crate::Scalable;
+use crate::vector::V128;
+use crate::{Scalable, SIMD128};
+
+use core::mem::transmute;
 
 #[inline(always)]
 pub fn u8x16xn_lookup<S, V>(s: S, lut: V, x: V) -> V
@@ -19,3 +22,37 @@ where
 
     unreachable!()
 }
+
+const COMPRESS_TABLE: &[u64; 256] = &{
+    let mut table = [0; 256];
+    let mut m = 0;
+    while m < 256 {
+        let mut idx = [0x80; 8];
+        let mut i = 0;
+        let mut j = 0;
+        while i < 8 {
+            if m & (1 << i) == 0 {
+                idx[j] = i as u8;
+                j += 1;
+            }
+            i += 1;
+        }
+        table[m] = u64::from_le_bytes(idx);
+        m += 1;
+    }
+    table
+};
+
+/// Moves the bytes whose `mask` bits are clear to the front of each half.
+///
+/// Returns the shuffled vector and the number of bytes kept in the low half.
+/// The kept bytes are `y[..n]` and `y[8..(8 + 16 - mask.count_ones() - n)]`.
+#[inline(always)]
+pub fn u8x16_compress<S: SIMD128>(s: S, x: V128, mask: u16) -> (V128, usize) {
+    let lo = COMPRESS_TABLE[(mask & 0xff) as usize];
+    let hi = COMPRESS_TABLE[(mask >> 8) as usize] | 0x0808_0808_0808_0808;
+    let idx = [lo.to_le_bytes(), hi.to_le_bytes()];
+    let idx = V128::from_bytes(unsafe { transmute::<[[u8; 8]; 2], [u8; 16]>(idx) });
+    let n = 8 - (mask & 0xff).count_ones() as usize;
+    (s.u8x16_swizzle(x, idx), n)
+}
@@ -43,7 +43,7 @@ fn u8x16_any_zero() {
         if let Some(s) = detect::<WASM128>() {
             return s.u8x16_any_zero(a);
         }
-        a.as_bytes().iter().any(|&x| x == 0)
+        a.as_bytes().contains(&0)
     }
 
     fn test(a: [u8; 16], expected: bool) {